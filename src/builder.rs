@@ -0,0 +1,513 @@
+use super::errors::SportMonksError;
+use super::gateway::{Gateway, AsyncGateway, DEFAULT_BASE_URL};
+use super::{Client, AsyncClient};
+use super::retry::RetryPolicy;
+use super::rate_limit::RateLimiter;
+use super::coercion::Coercion;
+use super::cache::{Cache, CachePolicy, LruCache};
+use super::cassette::Cassette;
+use super::transport::Transport;
+use super::middleware::Middleware;
+use reqwest::{Proxy, Client as HttpClient};
+use reqwest::async::{Client as AsyncHttpClient};
+use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Configures the HTTP layer shared by every gateway of a `Client` or `AsyncClient`.
+///
+/// When a pre-built HTTP client or a `Transport` is supplied, it is used as is and the timeout,
+/// user agent and proxy settings are ignored for that flavour of client. A rate limiter
+/// or cache is shared by all the gateways of the built client.
+pub struct ClientBuilder {
+    api_key: String,
+    base_url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<Proxy>,
+    http_client: Option<HttpClient>,
+    async_http_client: Option<AsyncHttpClient>,
+    transport: Option<Arc<dyn Transport>>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    coercion: Coercion,
+    cache: Option<Arc<dyn Cache>>,
+    cache_policy: Option<CachePolicy>,
+    cassette: Option<Arc<Cassette>>,
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl ClientBuilder {
+    pub fn new<S: Into<String>>(api_key: S) -> ClientBuilder {
+        ClientBuilder {
+            api_key: api_key.into(),
+            base_url: DEFAULT_BASE_URL.into(),
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxy: None,
+            http_client: None,
+            async_http_client: None,
+            transport: None,
+            retry_policy: RetryPolicy::none(),
+            rate_limiter: None,
+            coercion: Coercion::default(),
+            cache: None,
+            cache_policy: None,
+            cassette: None,
+            middleware: Vec::new(),
+        }
+    }
+
+    pub fn base_url<S: Into<String>>(mut self, base_url: S) -> ClientBuilder {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> ClientBuilder {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn proxy(mut self, proxy: Proxy) -> ClientBuilder {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    pub fn http_client(mut self, http_client: HttpClient) -> ClientBuilder {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    pub fn async_http_client(mut self, http_client: AsyncHttpClient) -> ClientBuilder {
+        self.async_http_client = Some(http_client);
+        self
+    }
+
+    /// Sends the requests of the blocking client through `transport`.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> ClientBuilder {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> ClientBuilder {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> ClientBuilder {
+        self.rate_limiter = Some(Arc::new(rate_limiter));
+        self
+    }
+
+    pub fn coercion(mut self, coercion: Coercion) -> ClientBuilder {
+        self.coercion = coercion;
+        self
+    }
+
+    /// Caches responses in `cache`, with the default `CachePolicy` unless one is set.
+    pub fn cache<C: Cache + 'static>(mut self, cache: C) -> ClientBuilder {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Caches responses according to `policy`, in an `LruCache` unless a cache is set.
+    pub fn cache_policy(mut self, policy: CachePolicy) -> ClientBuilder {
+        self.cache_policy = Some(policy);
+        self
+    }
+
+    /// Records responses to, or replays them from, `cassette`.
+    pub fn cassette(mut self, cassette: Cassette) -> ClientBuilder {
+        self.cassette = Some(Arc::new(cassette));
+        self
+    }
+
+    /// Adds `middleware` to the hooks run around every request of the blocking client.
+    pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> ClientBuilder {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    pub fn build(self) -> Result<Client, SportMonksError> {
+        let gateway = self.build_gateway()?;
+        Ok(Client::with_gateway(gateway))
+    }
+
+    pub fn build_async(self) -> Result<AsyncClient, SportMonksError> {
+        let gateway = self.build_async_gateway()?;
+        Ok(AsyncClient::with_gateway(gateway))
+    }
+
+    pub fn build_gateway(self) -> Result<Gateway, SportMonksError> {
+        let transport: Arc<dyn Transport> = match (self.transport.clone(), self.http_client.clone()) {
+            (Some(transport), _) => transport,
+            (None, Some(http_client)) => Arc::new(http_client),
+            (None, None) => {
+                let mut builder = HttpClient::builder().default_headers(self.default_headers()?);
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(ref proxy) = self.proxy {
+                    builder = builder.proxy(proxy.clone());
+                }
+                Arc::new(builder.build()?)
+            }
+        };
+        let mut gateway = Gateway::with_transport(transport, self.api_key, self.base_url).retry_policy(self.retry_policy).coercion(self.coercion);
+        if let Some(rate_limiter) = self.rate_limiter {
+            gateway = gateway.rate_limiter(rate_limiter);
+        }
+        if self.cache.is_some() || self.cache_policy.is_some() {
+            let cache = self.cache.unwrap_or_else(|| Arc::new(LruCache::default()));
+            gateway = gateway.cache(cache, self.cache_policy.unwrap_or_default());
+        }
+        if let Some(cassette) = self.cassette {
+            gateway = gateway.cassette(cassette);
+        }
+        for middleware in self.middleware {
+            gateway = gateway.middleware(middleware);
+        }
+        Ok(gateway)
+    }
+
+    pub fn build_async_gateway(self) -> Result<AsyncGateway, SportMonksError> {
+        let http_client = match self.async_http_client {
+            Some(ref http_client) => http_client.clone(),
+            None => {
+                let mut builder = AsyncHttpClient::builder().default_headers(self.default_headers()?);
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(ref proxy) = self.proxy {
+                    builder = builder.proxy(proxy.clone());
+                }
+                builder.build()?
+            }
+        };
+        let mut gateway = AsyncGateway::with_client(http_client, self.api_key, self.base_url).retry_policy(self.retry_policy).coercion(self.coercion);
+        if let Some(rate_limiter) = self.rate_limiter {
+            gateway = gateway.rate_limiter(rate_limiter);
+        }
+        if self.cache.is_some() || self.cache_policy.is_some() {
+            let cache = self.cache.unwrap_or_else(|| Arc::new(LruCache::default()));
+            gateway = gateway.cache(cache, self.cache_policy.unwrap_or_default());
+        }
+        if let Some(cassette) = self.cassette {
+            gateway = gateway.cassette(cassette);
+        }
+        Ok(gateway)
+    }
+
+    fn default_headers(&self) -> Result<HeaderMap, SportMonksError> {
+        let mut headers = HeaderMap::new();
+        if let Some(ref user_agent) = self.user_agent {
+            let value = HeaderValue::from_str(user_agent)
+                .map_err(|error| SportMonksError::Config(error.to_string()))?;
+            headers.insert(USER_AGENT, value);
+        }
+        Ok(headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rate_limit::Exhaustion;
+    use super::super::gateway::Options;
+    use mockito::{mock, SERVER_URL};
+    use super::super::cache::FileCache;
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+    use std::process;
+    use tokio::runtime::Runtime;
+
+    #[test]
+    fn it_threads_the_base_url_and_user_agent_into_every_gateway() {
+        let body = fs::read_to_string(Path::new("src/support/continents/all.json")).expect("Fixtures:");
+        let m = mock("GET", "/staging/continents?api_token=1234")
+          .match_header("user-agent", "ingestion/1.0")
+          .with_status(200)
+          .with_body(body)
+          .create();
+
+        let client = ClientBuilder::new("1234")
+            .base_url(format!("{}/staging/", SERVER_URL))
+            .user_agent("ingestion/1.0")
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+        let result = client.contients.all();
+
+        m.assert();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_builds_async_clients_over_a_prebuilt_http_client() {
+        let body = fs::read_to_string(Path::new("src/support/continents/all.json")).expect("Fixtures:");
+        let m = mock("GET", "/proxy/continents?api_token=1234")
+          .with_status(200)
+          .with_body(body)
+          .create();
+
+        let client = ClientBuilder::new("1234")
+            .base_url(format!("{}/proxy", SERVER_URL))
+            .async_http_client(AsyncHttpClient::new())
+            .build_async()
+            .unwrap();
+        let mut runtime = Runtime::new().unwrap();
+        let result = runtime.block_on(client.contients.all());
+
+        m.assert();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_retries_transient_failures_up_to_the_configured_attempts() {
+        let m = mock("GET", "/retry/leagues?api_token=1234")
+          .with_status(503)
+          .with_body("Service Unavailable")
+          .expect(3)
+          .create();
+
+        let client = ClientBuilder::new("1234")
+            .base_url(format!("{}/retry", SERVER_URL))
+            .retry_policy(RetryPolicy::new().max_attempts(3).base_delay(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+        let result = client.leagues.all();
+
+        m.assert();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_does_not_retry_client_errors() {
+        let m = mock("GET", "/retry/seasons?api_token=1234")
+          .with_status(404)
+          .with_body(r#"{"error":{"message":"Not found.","code":404}}"#)
+          .create();
+
+        let client = ClientBuilder::new("1234")
+            .base_url(format!("{}/retry", SERVER_URL))
+            .retry_policy(RetryPolicy::new().max_attempts(3).base_delay(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+        let result = client.seasons.all();
+
+        m.assert();
+        assert_eq!(result.unwrap_err().code(), 404);
+    }
+
+    #[test]
+    fn it_retries_transient_failures_asynchronously() {
+        let m = mock("GET", "/retry/countries?api_token=1234")
+          .with_status(502)
+          .with_body("Bad Gateway")
+          .expect(2)
+          .create();
+
+        let client = ClientBuilder::new("1234")
+            .base_url(format!("{}/retry", SERVER_URL))
+            .retry_policy(RetryPolicy::new().max_attempts(2).base_delay(Duration::from_millis(1)))
+            .build_async()
+            .unwrap();
+        let mut runtime = Runtime::new().unwrap();
+        let result = runtime.block_on(client.countries.all());
+
+        m.assert();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_shares_the_rate_limit_budget_across_gateways() {
+        let body = fs::read_to_string(Path::new("src/support/continents/all.json")).expect("Fixtures:");
+        let m = mock("GET", "/limited/continents?api_token=1234")
+          .with_status(200)
+          .with_body(body)
+          .expect(1)
+          .create();
+
+        let client = ClientBuilder::new("1234")
+            .base_url(format!("{}/limited", SERVER_URL))
+            .rate_limiter(RateLimiter::new(1, Duration::from_secs(3600)).on_exhaustion(Exhaustion::Error))
+            .build()
+            .unwrap();
+        assert!(client.contients.all().is_ok());
+        let result = client.countries.all();
+
+        m.assert();
+        assert_eq!(result.unwrap_err().code(), 429);
+    }
+
+    #[test]
+    fn it_serves_repeated_requests_from_the_cache() {
+        let body = fs::read_to_string(Path::new("src/support/continents/all.json")).expect("Fixtures:");
+        let m = mock("GET", "/cached/continents?api_token=1234")
+          .with_status(200)
+          .with_body(body)
+          .expect(2)
+          .create();
+
+        let client = ClientBuilder::new("1234")
+            .base_url(format!("{}/cached", SERVER_URL))
+            .cache_policy(CachePolicy::default())
+            .build()
+            .unwrap();
+        let first = client.contients.all().unwrap();
+        let second = client.contients.all().unwrap();
+        let fresh = client.contients.all_with(Options::builder().bypass_cache()).unwrap();
+
+        m.assert();
+        assert_eq!(first.data, second.data);
+        assert_eq!(first.data, fresh.data);
+    }
+
+    #[test]
+    fn it_does_not_cache_failures_asynchronously() {
+        let body = fs::read_to_string(Path::new("src/support/leagues/all.json")).expect("Fixtures:");
+        let failure = mock("GET", "/flaky/leagues?api_token=1234")
+          .with_status(503)
+          .with_body("unavailable")
+          .create();
+
+        let client = ClientBuilder::new("1234")
+            .base_url(format!("{}/flaky", SERVER_URL))
+            .cache(LruCache::new(8))
+            .build_async()
+            .unwrap();
+        let mut runtime = Runtime::new().unwrap();
+        assert!(runtime.block_on(client.leagues.all()).is_err());
+        failure.assert();
+
+        let success = mock("GET", "/flaky/leagues?api_token=1234")
+          .with_status(200)
+          .with_body(body)
+          .expect(1)
+          .create();
+        assert!(runtime.block_on(client.leagues.all()).is_ok());
+        assert!(runtime.block_on(client.leagues.all()).is_ok());
+        success.assert();
+    }
+
+    #[test]
+    fn it_keeps_finished_fixtures_on_disk_for_offline_runs() {
+        let dir = env::temp_dir().join(format!("sport_monks_archive_{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let body = fs::read_to_string(Path::new("src/support/fixtures/find_with_many_stuff.json")).expect("Fixtures:");
+        let m = mock("GET", "/archive/fixtures/42?api_token=1234")
+          .with_status(200)
+          .with_body(body)
+          .expect(1)
+          .create();
+
+        let client = ClientBuilder::new("1234")
+            .base_url(format!("{}/archive", SERVER_URL))
+            .cache(FileCache::new(&dir).unwrap())
+            .cache_policy(CachePolicy::new(Duration::from_secs(0)))
+            .build()
+            .unwrap();
+        let fetched = client.fixtures.find(42).unwrap();
+        m.assert();
+
+        let offline = ClientBuilder::new("5678")
+            .base_url(format!("{}/unreachable", SERVER_URL))
+            .cache(FileCache::new(&dir).unwrap())
+            .cache_policy(CachePolicy::default().offline())
+            .build()
+            .unwrap();
+        assert_eq!(offline.fixtures.find(42).unwrap().data, fetched.data);
+        match offline.fixtures.find(43).unwrap_err() {
+            SportMonksError::NotCached { key } => assert_eq!(key, "/fixtures/43"),
+            other => panic!("unexpected error {:?}", other)
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn it_replays_recorded_responses_without_the_token() {
+        let path = env::temp_dir().join(format!("sport_monks_cassette_{}.json", process::id()));
+        let body = fs::read_to_string(Path::new("src/support/continents/all.json")).expect("Fixtures:");
+        let m = mock("GET", "/recorded/continents?api_token=secret-token")
+          .with_status(200)
+          .with_body(body.replacen("\"Europe\"", "\"Europe?api_token=secret-token\"", 1))
+          .create();
+        let missing = mock("GET", "/recorded/continents/1?api_token=secret-token")
+          .with_status(404)
+          .with_body(r#"{"error":{"message":"Not found.","code":404}}"#)
+          .create();
+
+        let recording = ClientBuilder::new("secret-token")
+            .base_url(format!("{}/recorded", SERVER_URL))
+            .cassette(Cassette::record(&path))
+            .build()
+            .unwrap();
+        let recorded = recording.contients.all().unwrap();
+        assert_eq!(recording.contients.find(1).unwrap_err().code(), 404);
+        m.assert();
+        missing.assert();
+        let cassette = fs::read_to_string(&path).unwrap();
+        assert!(!cassette.contains("secret-token"));
+        assert!(cassette.contains("REDACTED"));
+
+        let replaying = ClientBuilder::new("other-token")
+            .base_url(format!("{}/unreachable", SERVER_URL))
+            .cassette(Cassette::replay(&path).unwrap())
+            .build_async()
+            .unwrap();
+        let mut runtime = Runtime::new().unwrap();
+        assert_eq!(runtime.block_on(replaying.contients.all()).unwrap().data.len(), recorded.data.len());
+        assert_eq!(runtime.block_on(replaying.contients.find(1)).unwrap_err().code(), 404);
+        match runtime.block_on(replaying.contients.find(2)).unwrap_err() {
+            SportMonksError::Unrecorded { key, .. } => assert_eq!(key, "/continents/2"),
+            other => panic!("unexpected error {:?}", other)
+        }
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn it_tunes_the_rate_limiter_from_the_plan_asynchronously() {
+        let body = fs::read_to_string(Path::new("src/support/leagues/all.json")).expect("Fixtures:");
+        let m = mock("GET", "/tuned/leagues?api_token=1234")
+          .with_status(200)
+          .with_body(body)
+          .create();
+
+        let client = ClientBuilder::new("1234")
+            .base_url(format!("{}/tuned", SERVER_URL))
+            .rate_limiter(RateLimiter::from_plan())
+            .build_async()
+            .unwrap();
+        let mut runtime = Runtime::new().unwrap();
+        let result = runtime.block_on(client.leagues.all());
+
+        m.assert();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_rejects_invalid_user_agents() {
+        let result = ClientBuilder::new("1234").user_agent("bad\nagent").build();
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,439 @@
+use super::models::FixtureStatus;
+use serde_json::{self, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const DEFAULT_CAPACITY: usize = 256;
+const REFERENCE_DATA_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+const LIVESCORES_TTL: Duration = Duration::from_secs(5);
+const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+/// Storage for raw response bodies, keyed by endpoint path and normalized query.
+///
+/// Implementations are shared by every gateway of a client, possibly across threads.
+pub trait Cache: Send + Sync {
+    /// The body stored under `key`, unless it expired.
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// The body stored under `key`, even if it expired. Used in offline mode.
+    fn get_stale(&self, key: &str) -> Option<String> {
+        self.get(key)
+    }
+
+    /// Stores `body` under `key` for `ttl`, or for good when `ttl` is `None`.
+    fn put(&self, key: &str, body: &str, ttl: Option<Duration>);
+}
+
+/// How long the responses of each endpoint are kept.
+///
+/// Endpoints are matched on their longest configured path prefix, so `/leagues` covers
+/// `/leagues/8` as well. A zero TTL disables caching for the endpoint.
+///
+/// A fixture looked up by id once it is finished, and a season looked up by id once it is
+/// over, never change and are kept for good, whatever the TTL of their endpoint. Lists, such
+/// as livescores or head to heads, always expire: later fixtures may join them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachePolicy {
+    ttls: Vec<(String, Duration)>,
+    default_ttl: Duration,
+    keep_history: bool,
+    offline: bool,
+}
+
+impl Default for CachePolicy {
+    /// A day for continents, countries and leagues, five seconds for livescores and a minute
+    /// for everything else.
+    fn default() -> CachePolicy {
+        CachePolicy::new(DEFAULT_TTL)
+            .ttl("/continents", REFERENCE_DATA_TTL)
+            .ttl("/countries", REFERENCE_DATA_TTL)
+            .ttl("/leagues", REFERENCE_DATA_TTL)
+            .ttl("/livescores", LIVESCORES_TTL)
+    }
+}
+
+impl CachePolicy {
+    /// A policy without per-endpoint TTLs.
+    pub fn new(default_ttl: Duration) -> CachePolicy {
+        CachePolicy { ttls: Vec::new(), default_ttl, keep_history: true, offline: false }
+    }
+
+    pub fn ttl<S: Into<String>>(mut self, prefix: S, ttl: Duration) -> CachePolicy {
+        let prefix = prefix.into();
+        self.ttls.retain(|(existing, _)| *existing != prefix);
+        self.ttls.push((prefix, ttl));
+        self
+    }
+
+    pub fn default_ttl(mut self, ttl: Duration) -> CachePolicy {
+        self.default_ttl = ttl;
+        self
+    }
+
+    /// Expires finished fixtures and past seasons like any other response.
+    pub fn expire_history(mut self) -> CachePolicy {
+        self.keep_history = false;
+        self
+    }
+
+    /// Serves every request from the cache, expired entries included, and fails with
+    /// `SportMonksError::NotCached` instead of reaching the network.
+    pub fn offline(mut self) -> CachePolicy {
+        self.offline = true;
+        self
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    pub fn ttl_for(&self, path: &str) -> Duration {
+        self.ttls.iter()
+            .filter(|(prefix, _)| path == prefix || path.starts_with(&format!("{}/", prefix)))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default_ttl, |(_, ttl)| *ttl)
+    }
+}
+
+/// The cache key of a request: its path followed by its parameters, sorted so that the order
+/// they were added in, including the order of includes, does not matter.
+pub fn cache_key(path: &str, query: &[(String, String)]) -> String {
+    let mut params: Vec<String> = query.iter()
+        .map(|(name, value)| if name == "include" {
+            let mut includes: Vec<&str> = value.split(',').collect();
+            includes.sort_unstable();
+            format!("{}={}", name, includes.join(","))
+        } else {
+            format!("{}={}", name, value)
+        })
+        .collect();
+    params.sort();
+    if params.is_empty() {
+        path.to_string()
+    } else {
+        format!("{}?{}", path, params.join("&"))
+    }
+}
+
+/// A cache backend along with the policy deciding what goes into it.
+#[derive(Clone)]
+pub(crate) struct ResponseCache {
+    pub backend: Arc<dyn Cache>,
+    pub policy: CachePolicy,
+}
+
+impl ResponseCache {
+    pub fn get(&self, key: &str) -> Option<String> {
+        if self.policy.offline {
+            self.backend.get_stale(key)
+        } else {
+            self.backend.get(key)
+        }
+    }
+
+    pub fn put(&self, path: &str, key: &str, body: &str) {
+        if self.policy.keep_history && is_history(path, body) {
+            self.backend.put(key, body, None);
+            return;
+        }
+        let ttl = self.policy.ttl_for(path);
+        if ttl > Duration::from_secs(0) {
+            self.backend.put(key, body, Some(ttl));
+        }
+    }
+}
+
+/// Whether the response is a finished fixture or a past season, looked up by id.
+fn is_history(path: &str, body: &str) -> bool {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let resource = match segments[..] {
+        [resource, id] if id.parse::<i64>().is_ok() => resource,
+        _ => return false,
+    };
+    let payload: Value = match serde_json::from_str(body) {
+        Ok(payload) => payload,
+        Err(_) => return false,
+    };
+    let record = &payload["data"];
+    match resource {
+        "fixtures" => record["time"]["status"].as_str()
+            .is_some_and(|status| status.parse::<FixtureStatus>().is_ok_and(|status| status.is_finished())),
+        "seasons" => record["is_current_season"] == Value::Bool(false),
+        _ => false,
+    }
+}
+
+/// In-memory cache evicting the least recently used entry once full.
+pub struct LruCache {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+
+#[derive(Default)]
+struct LruState {
+    entries: HashMap<String, LruEntry>,
+    clock: u64,
+}
+
+struct LruEntry {
+    body: String,
+    expires_at: Option<Instant>,
+    used: u64,
+}
+
+impl LruEntry {
+    fn is_fresh(&self, now: Instant) -> bool {
+        self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+}
+
+impl Default for LruCache {
+    fn default() -> LruCache {
+        LruCache::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl LruCache {
+    pub fn new(capacity: usize) -> LruCache {
+        LruCache { capacity: capacity.max(1), state: Mutex::new(LruState::default()) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> ::std::sync::MutexGuard<'_, LruState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Cache for LruCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let mut state = self.lock();
+        state.clock += 1;
+        let clock = state.clock;
+        let fresh = match state.entries.get_mut(key) {
+            None => return None,
+            Some(entry) if entry.is_fresh(Instant::now()) => {
+                entry.used = clock;
+                Some(entry.body.clone())
+            },
+            Some(_) => None,
+        };
+        if fresh.is_none() {
+            state.entries.remove(key);
+        }
+        fresh
+    }
+
+    fn get_stale(&self, key: &str) -> Option<String> {
+        self.lock().entries.get(key).map(|entry| entry.body.clone())
+    }
+
+    fn put(&self, key: &str, body: &str, ttl: Option<Duration>) {
+        let mut state = self.lock();
+        state.clock += 1;
+        let entry = LruEntry { body: body.to_string(), expires_at: ttl.map(|ttl| Instant::now() + ttl), used: state.clock };
+        state.entries.insert(key.to_string(), entry);
+        if state.entries.len() > self.capacity {
+            let now = Instant::now();
+            state.entries.retain(|_, entry| entry.is_fresh(now));
+        }
+        while state.entries.len() > self.capacity {
+            let oldest = state.entries.iter().min_by_key(|(_, entry)| entry.used).map(|(key, _)| key.clone());
+            match oldest {
+                Some(oldest) => state.entries.remove(&oldest),
+                None => break,
+            };
+        }
+    }
+}
+
+/// Cache keeping one JSON file per response in a directory, so that responses outlive the
+/// process and batch jobs can run again offline.
+///
+/// Entries are not evicted; expired files are overwritten on the next fetch of their key.
+pub struct FileCache {
+    dir: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FileEntry {
+    key: String,
+    /// Seconds since the Unix epoch, `None` for responses that never change.
+    expires_at: Option<u64>,
+    body: String,
+}
+
+impl FileCache {
+    /// Uses `dir` as the cache directory, creating it if needed.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> io::Result<FileCache> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(FileCache { dir })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", fnv1a(key)))
+    }
+
+    fn read(&self, key: &str) -> Option<FileEntry> {
+        let contents = fs::read_to_string(self.path(key)).ok()?;
+        serde_json::from_str::<FileEntry>(&contents).ok().filter(|entry| entry.key == key)
+    }
+}
+
+impl Cache for FileCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let entry = self.read(key)?;
+        match entry.expires_at {
+            Some(expires_at) if expires_at <= unix_now() => None,
+            _ => Some(entry.body),
+        }
+    }
+
+    fn get_stale(&self, key: &str) -> Option<String> {
+        self.read(key).map(|entry| entry.body)
+    }
+
+    fn put(&self, key: &str, body: &str, ttl: Option<Duration>) {
+        let entry = FileEntry { key: key.to_string(), expires_at: ttl.map(|ttl| unix_now() + ttl.as_secs()), body: body.to_string() };
+        let path = self.path(key);
+        let partial = path.with_extension("partial");
+        let written = serde_json::to_string(&entry).ok()
+            .and_then(|contents| fs::write(&partial, contents).ok())
+            .and_then(|_| fs::rename(&partial, &path).ok());
+        if written.is_none() {
+            let _ = fs::remove_file(&partial);
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|now| now.as_secs()).unwrap_or(0)
+}
+
+/// A hash that stays the same across Rust releases, unlike `DefaultHasher`, to name cache files.
+fn fnv1a(key: &str) -> u64 {
+    key.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+    use std::thread;
+
+    #[test]
+    fn it_evicts_the_least_recently_used_entry() {
+        let cache = LruCache::new(2);
+        cache.put("a", "1", Some(DEFAULT_TTL));
+        cache.put("b", "2", Some(DEFAULT_TTL));
+        assert_eq!(cache.get("a"), Some("1".into()));
+        cache.put("c", "3", Some(DEFAULT_TTL));
+
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("a"), Some("1".into()));
+        assert_eq!(cache.get("c"), Some("3".into()));
+    }
+
+    #[test]
+    fn it_expires_entries() {
+        let cache = LruCache::default();
+        cache.put("a", "1", Some(Duration::from_millis(10)));
+        thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(cache.get_stale("a"), Some("1".into()));
+        assert_eq!(cache.get("a"), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn it_persists_entries_on_disk() {
+        let dir = env::temp_dir().join(format!("sport_monks_file_cache_{}", process::id()));
+        let cache = FileCache::new(&dir).unwrap();
+        cache.put("/leagues", "fresh", Some(DEFAULT_TTL));
+        cache.put("/seasons", "stale", Some(Duration::from_secs(0)));
+        cache.put("/fixtures/1", "final", None);
+
+        let reopened = FileCache::new(&dir).unwrap();
+        assert_eq!(reopened.get("/leagues"), Some("fresh".into()));
+        assert_eq!(reopened.get("/seasons"), None);
+        assert_eq!(reopened.get_stale("/seasons"), Some("stale".into()));
+        assert_eq!(reopened.get("/fixtures/1"), Some("final".into()));
+        assert_eq!(reopened.get("/fixtures/2"), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Keeps the TTL each key was stored with.
+    #[derive(Default)]
+    struct TtlRecorder {
+        ttls: Mutex<HashMap<String, Option<Duration>>>,
+    }
+
+    impl Cache for TtlRecorder {
+        fn get(&self, _key: &str) -> Option<String> {
+            None
+        }
+
+        fn put(&self, key: &str, _body: &str, ttl: Option<Duration>) {
+            self.ttls.lock().unwrap().insert(key.to_string(), ttl);
+        }
+    }
+
+    #[test]
+    fn it_recognises_responses_that_never_change() {
+        assert!(is_history("/fixtures/1", r#"{"data": {"time": {"status": "FT_PEN"}}}"#));
+        assert!(is_history("/seasons/16", r#"{"data": {"id": 16, "is_current_season": false}}"#));
+        assert!(!is_history("/fixtures/1", r#"{"data": {"time": {"status": "LIVE"}}}"#));
+        assert!(!is_history("/seasons/16", r#"{"data": {"id": 16, "is_current_season": true}}"#));
+        assert!(!is_history("/fixtures/date/2019-02-16", r#"{"data": [{"time": {"status": "FT"}}]}"#));
+        assert!(!is_history("/fixtures/multi/1,2", r#"{"data": [{"time": {"status": "FT"}}]}"#));
+    }
+
+    #[test]
+    fn it_keeps_expiring_lists_of_finished_fixtures() {
+        let backend = Arc::new(TtlRecorder::default());
+        let cache = ResponseCache { backend: backend.clone(), policy: CachePolicy::default() };
+        let head_to_head = fs::read_to_string("src/support/head2head/find.json").unwrap();
+        let livescores = r#"{"data": [{"id": 1, "time": {"status": "FT"}}]}"#;
+        let fixture = r#"{"data": {"id": 1, "time": {"status": "FT"}}}"#;
+
+        cache.put("/head2head/67/9", "/head2head/67/9", &head_to_head);
+        cache.put("/livescores/now", "/livescores/now", livescores);
+        cache.put("/fixtures/1", "/fixtures/1", fixture);
+
+        let ttls = backend.ttls.lock().unwrap();
+        assert_eq!(ttls["/head2head/67/9"], Some(DEFAULT_TTL));
+        assert_eq!(ttls["/livescores/now"], Some(LIVESCORES_TTL));
+        assert_eq!(ttls["/fixtures/1"], None);
+    }
+
+    #[test]
+    fn it_picks_the_ttl_of_the_longest_matching_prefix() {
+        let policy = CachePolicy::default().ttl("/leagues/8", Duration::from_secs(1));
+
+        assert_eq!(policy.ttl_for("/leagues"), REFERENCE_DATA_TTL);
+        assert_eq!(policy.ttl_for("/leagues/8"), Duration::from_secs(1));
+        assert_eq!(policy.ttl_for("/livescores/now"), LIVESCORES_TTL);
+        assert_eq!(policy.ttl_for("/leaguesx"), DEFAULT_TTL);
+    }
+
+    #[test]
+    fn it_normalizes_the_query_into_the_key() {
+        let query = vec![("page".to_string(), "2".to_string()), ("include".to_string(), "localTeam,goals".to_string())];
+        let reordered = vec![("include".to_string(), "goals,localTeam".to_string()), ("page".to_string(), "2".to_string())];
+
+        assert_eq!(cache_key("/fixtures/1", &query), cache_key("/fixtures/1", &reordered));
+        assert_eq!(cache_key("/fixtures/1", &query), "/fixtures/1?include=goals,localTeam&page=2");
+    }
+}
@@ -0,0 +1,126 @@
+use super::errors::SportMonksError;
+use reqwest::StatusCode;
+use serde_json;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+const REDACTED: &str = "REDACTED";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CassetteMode {
+    /// Requests reach the API and every response is appended to the cassette file.
+    Record,
+    /// Requests are answered from the cassette file and never reach the API.
+    Replay,
+}
+
+/// A file of recorded responses, to run tests deterministically and without network.
+///
+/// Requests are matched on their path and normalized query, without the API token. When the
+/// same request was recorded several times, the responses are replayed in order and the last
+/// one is repeated. The API token is redacted from recorded bodies.
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Mutex<CassetteState>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    #[serde(skip)]
+    replayed: HashMap<String, usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Interaction {
+    pub request: String,
+    pub status: u16,
+    pub retry_after: Option<u64>,
+    pub body: String,
+}
+
+impl Interaction {
+    pub fn status(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after.map(Duration::from_secs)
+    }
+}
+
+impl Cassette {
+    /// Records into the file at `path`, replacing what it held.
+    pub fn record<P: Into<PathBuf>>(path: P) -> Cassette {
+        Cassette { path: path.into(), mode: CassetteMode::Record, state: Mutex::new(CassetteState::default()) }
+    }
+
+    /// Replays the file at `path`.
+    pub fn replay<P: Into<PathBuf>>(path: P) -> Result<Cassette, SportMonksError> {
+        let path = path.into();
+        let state = fs::read_to_string(&path)
+            .map_err(|error| error.to_string())
+            .and_then(|contents| serde_json::from_str::<CassetteState>(&contents).map_err(|error| error.to_string()))
+            .map_err(|error| SportMonksError::Config(format!("cannot read cassette {}: {}", path.display(), error)))?;
+        Ok(Cassette { path, mode: CassetteMode::Replay, state: Mutex::new(state) })
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// The next recorded response to the request `key`.
+    pub(crate) fn play(&self, key: &str) -> Result<Interaction, SportMonksError> {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let recorded: Vec<&Interaction> = state.interactions.iter().filter(|interaction| interaction.request == key).collect();
+        if recorded.is_empty() {
+            return Err(SportMonksError::Unrecorded { key: key.to_string(), cassette: self.path.display().to_string() });
+        }
+        let played = state.replayed.get(key).cloned().unwrap_or(0);
+        let interaction = recorded[played.min(recorded.len() - 1)].clone();
+        state.replayed.insert(key.to_string(), played + 1);
+        Ok(interaction)
+    }
+
+    /// Appends a response to the cassette and saves it.
+    pub(crate) fn record_response(&self, key: &str, api_key: &str, status: StatusCode, retry_after: Option<Duration>, body: &str) {
+        let body = if api_key.is_empty() { body.to_string() } else { body.replace(api_key, REDACTED) };
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.interactions.push(Interaction {
+            request: key.to_string(),
+            status: status.as_u16(),
+            retry_after: retry_after.map(|retry_after| retry_after.as_secs()),
+            body,
+        });
+        if let Ok(contents) = serde_json::to_string_pretty(&*state) {
+            let _ = fs::write(&self.path, contents);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn it_replays_repeated_requests_in_order_then_repeats_the_last() {
+        let path = env::temp_dir().join(format!("sport_monks_sequence_{}.json", process::id()));
+        let recording = Cassette::record(&path);
+        recording.record_response("/livescores/now", "1234", StatusCode::OK, None, "first 1234");
+        recording.record_response("/livescores/now", "1234", StatusCode::TOO_MANY_REQUESTS, Some(Duration::from_secs(3)), "second");
+
+        let replaying = Cassette::replay(&path).unwrap();
+        assert_eq!(replaying.play("/livescores/now").unwrap().body, "first REDACTED");
+        let second = replaying.play("/livescores/now").unwrap();
+        assert_eq!((second.status(), second.retry_after()), (StatusCode::TOO_MANY_REQUESTS, Some(Duration::from_secs(3))));
+        assert_eq!(replaying.play("/livescores/now").unwrap().body, "second");
+        assert!(replaying.play("/livescores").is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
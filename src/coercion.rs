@@ -0,0 +1,100 @@
+use std::cell::RefCell;
+use std::mem;
+
+/// How numeric fields that SportMonks sends as strings are handled when the string is not a number
+/// (e.g. `"N/A"` or `"12.5%"` in player stats).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Coercion {
+    /// Use the field's default value and record a `CoercionWarning` on the response `Wrapper`.
+    #[default]
+    Lenient,
+    /// Fail the whole response with a deserialization error pointing at the field.
+    Strict,
+}
+
+/// A value that lenient coercion replaced by a default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoercionWarning {
+    pub value: String,
+    pub expected: &'static str,
+}
+
+struct State {
+    mode: Coercion,
+    warnings: Vec<CoercionWarning>,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = const { RefCell::new(State { mode: Coercion::Lenient, warnings: Vec::new() }) };
+}
+
+/// Runs `decode` with the given coercion mode on the current thread and returns what it produced
+/// along with the warnings recorded meanwhile.
+pub fn with_mode<T, F: FnOnce() -> T>(mode: Coercion, decode: F) -> (T, Vec<CoercionWarning>) {
+    let (previous_mode, previous_warnings) = STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        (mem::replace(&mut state.mode, mode), mem::take(&mut state.warnings))
+    });
+    let decoded = decode();
+    let warnings = STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        state.mode = previous_mode;
+        mem::replace(&mut state.warnings, previous_warnings)
+    });
+    (decoded, warnings)
+}
+
+pub(crate) fn mode() -> Coercion {
+    STATE.with(|state| state.borrow().mode)
+}
+
+pub(crate) fn warn(value: &str, expected: &'static str) {
+    STATE.with(|state| state.borrow_mut().warnings.push(CoercionWarning { value: value.to_string(), expected }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use models::{Coach, Goals, Round};
+    use serde_json;
+
+    const GOALS: &str = r#"{"position": 1, "season_id": 16, "player_id": 7, "team_id": 9, "stage_id": null, "goals": "N/A", "penalty_goals": "2", "type": "goals"}"#;
+
+    #[test]
+    fn it_defaults_garbage_and_records_a_warning_when_lenient() {
+        let (goals, warnings) = with_mode(Coercion::Lenient, || serde_json::from_str::<Goals>(GOALS));
+        let goals = goals.unwrap();
+
+        assert_eq!(goals.goals, 0);
+        assert_eq!(goals.penalty_goals, 2);
+        assert_eq!(warnings, vec![CoercionWarning { value: "N/A".into(), expected: "an integer" }]);
+    }
+
+    #[test]
+    fn it_fails_on_garbage_when_strict() {
+        let (goals, warnings) = with_mode(Coercion::Strict, || serde_json::from_str::<Goals>(GOALS));
+
+        assert!(goals.unwrap_err().to_string().contains("invalid value: string \"N/A\""));
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn it_leaves_missing_and_garbage_dates_empty() {
+        let (coach, warnings) = with_mode(Coercion::Lenient, || serde_json::from_str::<Coach>(r#"{"coach_id": 1, "team_id": 2, "country_id": 3, "common_name": "", "fullname": "", "firstname": "", "lastname": "", "nationality": "", "birthdate": "", "birthcountry": "", "birthplace": "", "image_path": ""}"#));
+        assert_eq!(coach.unwrap().birthdate, None);
+        assert!(warnings.is_empty());
+
+        let (round, warnings) = with_mode(Coercion::Lenient, || serde_json::from_str::<Round>(r#"{"id": 1, "name": 1, "league_id": 2, "season_id": 3, "stage_id": 4, "start": "soon", "end": "2018-09-19"}"#));
+        let round = round.unwrap();
+        assert_eq!(round.start, None);
+        assert_eq!(round.end, chrono::NaiveDate::from_ymd_opt(2018, 9, 19));
+        assert_eq!(warnings, vec![CoercionWarning { value: "soon".into(), expected: "a date" }]);
+    }
+
+    #[test]
+    fn it_restores_the_previous_mode() {
+        with_mode(Coercion::Strict, || ());
+
+        assert_eq!(mode(), Coercion::Lenient);
+    }
+}
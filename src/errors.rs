@@ -1,5 +1,46 @@
+use reqwest::StatusCode;
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
+
+/// Everything that can go wrong while talking to SportMonks.
+///
+/// The variants separate failures that never reached the API (`Transport`, `Timeout`, `Unreachable`),
+/// non-successful answers (`Http`, `Api`, `RateLimited`) and payloads the models could
+/// not decode (`Deserialize`), so callers can decide what is worth retrying or reporting.
+///
+/// The `api_token` of the URLs held by `Transport` and `Timeout` is redacted when the error is
+/// displayed or debug-printed.
+pub enum SportMonksError {
+    /// The request could not be sent or the response could not be read.
+    Transport(reqwest::Error),
+    /// The request did not complete within the configured timeout.
+    Timeout(reqwest::Error),
+    /// A custom `Transport` got no response for a reason worth retrying, such as a dropped connection.
+    Unreachable(Box<dyn Error + Send + Sync>),
+    /// A non-successful status whose body is not a SportMonks error payload.
+    Http { status: StatusCode, body: String },
+    /// The API answered with its `{"error": {"message", "code"}}` payload.
+    Api { status: StatusCode, code: u32, message: String },
+    /// Too many requests, either answered by the API or refused by the client-side limiter.
+    RateLimited { retry_after: Option<Duration> },
+    /// The response body of `endpoint` did not match the expected model at the JSON `path`.
+    Deserialize { endpoint: String, path: String, snippet: String, source: serde_json::Error },
+    /// Strict include checking was requested and `endpoint` left out some of the includes.
+    MissingIncludes { endpoint: String, missing: Vec<String> },
+    /// A paginated `endpoint` answered with another page than the one requested.
+    UnexpectedPage { endpoint: String, requested: i64, returned: i64 },
+    /// The replayed cassette holds no response for the request `key`.
+    Unrecorded { key: String, cassette: String },
+    /// The cache is offline and holds no response for the request `key`.
+    NotCached { key: String },
+    /// The request options failed validation and nothing was sent.
+    InvalidOptions(Vec<String>),
+    /// The client could not be built from the given settings.
+    Config(String),
+    /// The timer driving backoff or rate limit waits failed, usually on runtime shutdown.
+    Timer(tokio_timer::Error),
+}
 
 #[derive(Debug, Deserialize)]
 struct StandardError {
@@ -8,49 +49,217 @@ struct StandardError {
 }
 
 #[derive(Debug, Deserialize)]
-pub struct SportMonksError {
+struct ApiErrorBody {
     error: StandardError
 }
 
 impl SportMonksError {
-    pub fn new(code: u32, message: String) -> SportMonksError {
-        let error = StandardError { message, code };
-        SportMonksError { error }
+    /// Builds the error matching a non-successful response.
+    pub fn from_response(status: StatusCode, retry_after: Option<Duration>, body: &str) -> SportMonksError {
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return SportMonksError::RateLimited { retry_after };
+        }
+        match serde_json::from_str::<ApiErrorBody>(body) {
+            Ok(payload) => SportMonksError::Api { status, code: payload.error.code, message: payload.error.message },
+            Err(_) => SportMonksError::Http { status, body: body.to_string() }
+        }
     }
 
     pub fn message(&self) -> String {
-        self.error.message.to_string()
+        match *self {
+            SportMonksError::Api { ref message, .. } => message.to_string(),
+            _ => self.to_string()
+        }
     }
-    
+
+    /// The API error code, the HTTP status, 429 when rate limited, or 0 for client-side failures.
     pub fn code(&self) -> u32 {
-        self.error.code
+        match *self {
+            SportMonksError::Api { code, .. } => code,
+            SportMonksError::Http { status, .. } => u32::from(status.as_u16()),
+            SportMonksError::RateLimited { .. } => 429,
+            _ => 0
+        }
+    }
+
+    pub fn status(&self) -> Option<StatusCode> {
+        match *self {
+            SportMonksError::Http { status, .. } | SportMonksError::Api { status, .. } => Some(status),
+            SportMonksError::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            _ => None
+        }
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        match *self {
+            SportMonksError::RateLimited { retry_after } => retry_after,
+            _ => None
+        }
     }
 }
 
 impl Error for SportMonksError {
-    fn description(&self) -> &str {
-        self.error.description()
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SportMonksError::Transport(ref error) | SportMonksError::Timeout(ref error) => Some(error),
+            SportMonksError::Unreachable(ref error) => Some(&**error),
+            SportMonksError::Deserialize { ref source, .. } => Some(source),
+            SportMonksError::Timer(ref error) => Some(error),
+            _ => None
+        }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
-        Some(&self.error)
+impl fmt::Display for SportMonksError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SportMonksError::Transport(ref error) => write!(f, "transport error: {}", redact_token(&error.to_string())),
+            SportMonksError::Timeout(ref error) => write!(f, "request timed out: {}", redact_token(&error.to_string())),
+            SportMonksError::Unreachable(ref error) => write!(f, "API unreachable: {}", error),
+            SportMonksError::Http { status, ref body } => write!(f, "HTTP {}: {}", status, body),
+            SportMonksError::Api { code, ref message, .. } => write!(f, "SportMonks API error {}: {}", code, message),
+            SportMonksError::RateLimited { retry_after: Some(wait) } => write!(f, "rate limited, retry in {}ms", wait.as_millis()),
+            SportMonksError::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            SportMonksError::Deserialize { ref endpoint, ref path, ref snippet, ref source } =>
+                write!(f, "could not deserialize {} at `{}`: {} near `{}`", endpoint, path, source, snippet),
+            SportMonksError::MissingIncludes { ref endpoint, ref missing } =>
+                write!(f, "{} did not return the requested includes: {}", endpoint, missing.join(", ")),
+            SportMonksError::UnexpectedPage { ref endpoint, requested, returned } =>
+                write!(f, "{} answered page {} when page {} was requested", endpoint, returned, requested),
+            SportMonksError::Unrecorded { ref key, ref cassette } => write!(f, "{} was not recorded in cassette {}", key, cassette),
+            SportMonksError::NotCached { ref key } => write!(f, "offline and {} is not cached", key),
+            SportMonksError::InvalidOptions(ref problems) => write!(f, "invalid options: {}", problems.join("; ")),
+            SportMonksError::Config(ref message) => write!(f, "invalid client configuration: {}", message),
+            SportMonksError::Timer(ref error) => write!(f, "timer error: {}", error),
+        }
     }
 }
 
-impl fmt::Display for SportMonksError {
+impl fmt::Debug for SportMonksError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            SportMonksError::Transport(ref error) => f.debug_tuple("Transport").field(&Redacted(error)).finish(),
+            SportMonksError::Timeout(ref error) => f.debug_tuple("Timeout").field(&Redacted(error)).finish(),
+            SportMonksError::Unreachable(ref error) => f.debug_tuple("Unreachable").field(error).finish(),
+            SportMonksError::Http { status, ref body } => f.debug_struct("Http").field("status", &status).field("body", body).finish(),
+            SportMonksError::Api { status, code, ref message } =>
+                f.debug_struct("Api").field("status", &status).field("code", &code).field("message", message).finish(),
+            SportMonksError::RateLimited { retry_after } => f.debug_struct("RateLimited").field("retry_after", &retry_after).finish(),
+            SportMonksError::Deserialize { ref endpoint, ref path, ref snippet, ref source } => f.debug_struct("Deserialize")
+                .field("endpoint", endpoint).field("path", path).field("snippet", snippet).field("source", source).finish(),
+            SportMonksError::MissingIncludes { ref endpoint, ref missing } =>
+                f.debug_struct("MissingIncludes").field("endpoint", endpoint).field("missing", missing).finish(),
+            SportMonksError::UnexpectedPage { ref endpoint, requested, returned } =>
+                f.debug_struct("UnexpectedPage").field("endpoint", endpoint).field("requested", &requested).field("returned", &returned).finish(),
+            SportMonksError::Unrecorded { ref key, ref cassette } => f.debug_struct("Unrecorded").field("key", key).field("cassette", cassette).finish(),
+            SportMonksError::NotCached { ref key } => f.debug_struct("NotCached").field("key", key).finish(),
+            SportMonksError::InvalidOptions(ref problems) => f.debug_tuple("InvalidOptions").field(problems).finish(),
+            SportMonksError::Config(ref message) => f.debug_tuple("Config").field(message).finish(),
+            SportMonksError::Timer(ref error) => f.debug_tuple("Timer").field(error).finish(),
+        }
     }
 }
 
-impl fmt::Display for StandardError {
+/// Debug-prints a reqwest error with its `api_token` redacted.
+struct Redacted<'a>(&'a reqwest::Error);
+
+impl<'a> fmt::Debug for Redacted<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        f.write_str(&redact_token(&format!("{:?}", self.0)))
+    }
+}
+
+/// Replaces the value of every `api_token` query parameter in `message`.
+fn redact_token(message: &str) -> String {
+    const PARAM: &str = "api_token=";
+    let mut redacted = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find(PARAM) {
+        let value = &rest[start + PARAM.len()..];
+        let end = value.find(|c: char| c == '&' || c == '"' || c == '\'' || c == ')' || c.is_whitespace()).unwrap_or(value.len());
+        redacted.push_str(&rest[..start + PARAM.len()]);
+        redacted.push_str("REDACTED");
+        rest = &value[end..];
+    }
+    redacted.push_str(rest);
+    redacted
+}
+
+impl From<reqwest::Error> for SportMonksError {
+    fn from(error: reqwest::Error) -> SportMonksError {
+        if error.is_timeout() {
+            SportMonksError::Timeout(error)
+        } else {
+            SportMonksError::Transport(error)
+        }
     }
 }
 
-impl Error for StandardError {
-    fn description(&self) -> &str {
-        "ERROR!"
+impl From<tokio_timer::Error> for SportMonksError {
+    fn from(error: tokio_timer::Error) -> SportMonksError {
+        SportMonksError::Timer(error)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reads_api_error_payloads() {
+        let error = SportMonksError::from_response(StatusCode::FORBIDDEN, None, r#"{"error":{"message":"No access.","code":403}}"#);
+
+        match error {
+            SportMonksError::Api { status, code, ref message } => {
+                assert_eq!(status, StatusCode::FORBIDDEN);
+                assert_eq!(code, 403);
+                assert_eq!(message, "No access.");
+            },
+            ref other => panic!("unexpected error {:?}", other)
+        }
+        assert_eq!(error.to_string(), "SportMonks API error 403: No access.");
+    }
+
+    #[test]
+    fn it_keeps_the_status_and_body_of_other_failures() {
+        let error = SportMonksError::from_response(StatusCode::BAD_GATEWAY, None, "<html>Bad Gateway</html>");
+
+        match error {
+            SportMonksError::Http { status, ref body } => {
+                assert_eq!(status, StatusCode::BAD_GATEWAY);
+                assert_eq!(body, "<html>Bad Gateway</html>");
+            },
+            ref other => panic!("unexpected error {:?}", other)
+        }
+        assert_eq!(error.code(), 502);
+    }
+
+    #[test]
+    fn it_surfaces_the_retry_hint_of_rate_limited_responses() {
+        let error = SportMonksError::from_response(StatusCode::TOO_MANY_REQUESTS, Some(Duration::from_secs(30)), "");
+
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+        assert_eq!(error.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+    }
+
+    #[test]
+    fn it_redacts_api_tokens_from_urls() {
+        assert_eq!(redact_token("error sending https://x.test/v2/leagues?api_token=secret&include=country: refused"),
+            "error sending https://x.test/v2/leagues?api_token=REDACTED&include=country: refused");
+        assert_eq!(redact_token("url: Some(\"https://x.test/v2?api_token=secret\")"), "url: Some(\"https://x.test/v2?api_token=REDACTED\")");
+    }
+
+    #[test]
+    fn it_chains_deserialization_causes() {
+        let source = serde_json::from_str::<u32>("\"twelve\"").unwrap_err();
+        let error = SportMonksError::Deserialize {
+            endpoint: "/leagues/8".into(),
+            path: "data.id".into(),
+            snippet: "\"twelve\"".into(),
+            source,
+        };
+
+        assert!(error.source().is_some());
+        assert!(error.to_string().starts_with("could not deserialize /leagues/8 at `data.id`"));
+    }
+}
@@ -1,91 +1,718 @@
 use super::errors::SportMonksError;
+use super::retry::{RetryPolicy, Outcome};
+use super::rate_limit::{RateLimiter, Exhaustion, exhausted};
+use super::coercion::{self, Coercion};
+use super::models::{Wrapper, FixtureStatus};
+use super::includes::{IncludePath, IncludeReport};
+use super::cache::{self, Cache, CachePolicy, ResponseCache};
+use super::cassette::{self, Cassette, CassetteMode};
+use super::transport::{self, Transport, HttpRequest, HttpResponse};
+use super::middleware::{self, Middleware, RequestInfo, ResponseInfo};
 use serde::de::DeserializeOwned;
-use reqwest::{Response, Client};
-use std::error::Error;
+use reqwest::{Client, Method, StatusCode};
+use reqwest::async::{Client as AsyncClient, Response as AsyncResponse};
+use futures::{future, Future};
+use futures::future::{Either, Loop};
+use tokio_timer::Delay;
+use chrono_tz::Tz;
+use std::cmp;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const SNIPPET_RADIUS: usize = 40;
+const MAX_PER_PAGE: u32 = 150;
 
 #[cfg(not(test))]
-const BASE_URL: &str = "https://soccer.sportmonks.com/api/v2.0";
+pub const DEFAULT_BASE_URL: &str = "https://soccer.sportmonks.com/api/v2.0";
 #[cfg(test)]
-const BASE_URL: &str = mockito::SERVER_URL;
+pub const DEFAULT_BASE_URL: &str = mockito::SERVER_URL;
+
+/// A boxed future resolving to a SportMonks response, as returned by the async gateways.
+pub type SportMonksFuture<T> = Box<dyn Future<Item = T, Error = SportMonksError> + Send>;
 
+/// Sends the requests of the blocking client.
+///
+/// Cloning is cheap: clones share the transport and its connection pool, the rate limiter,
+/// the cache, the cassette and the middleware. Configuring a clone leaves the others as they are.
+#[derive(Clone)]
 pub struct Gateway {
-    http_client: Client,
-    api_key: String
+    shared: Arc<GatewayConfig>,
+}
+
+#[derive(Clone)]
+struct GatewayConfig {
+    transport: Arc<dyn Transport>,
+    api_key: String,
+    base_url: String,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    coercion: Coercion,
+    cache: Option<ResponseCache>,
+    cassette: Option<Arc<Cassette>>,
+    middleware: middleware::Chain
+}
+
+/// Sends the requests of the asynchronous client, sharing its state between clones like `Gateway`.
+#[derive(Clone)]
+pub struct AsyncGateway {
+    shared: Arc<AsyncGatewayConfig>,
+}
+
+#[derive(Clone)]
+struct AsyncGatewayConfig {
+    http_client: AsyncClient,
+    api_key: String,
+    base_url: String,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    coercion: Coercion,
+    cache: Option<ResponseCache>,
+    cassette: Option<Arc<Cassette>>
+}
+
+/// Query parameters of a request.
+///
+/// `I` is the include type of the endpoint the options are meant for (e.g. `FixtureInclude`),
+/// so that `with` only accepts relations the endpoint knows about. It is inferred from the
+/// gateway method the options are passed to.
+pub struct Options<I = ()> {
+    pub query: Vec<(String, String)>,
+    /// Overrides the gateway's coercion mode for this request.
+    pub coercion: Option<Coercion>,
+    /// Fails the request when a requested include is missing from the response.
+    pub strict_includes: bool,
+    /// Skips the cache lookup for this request; the fresh response is still cached.
+    pub bypass_cache: bool,
+    errors: Vec<String>,
+    includes: PhantomData<fn() -> I>
+}
+
+impl<I> Default for Options<I> {
+    fn default() -> Options<I> {
+        Options { query: Vec::new(), coercion: None, strict_includes: false, bypass_cache: false, errors: Vec::new(), includes: PhantomData }
+    }
 }
 
-#[derive(Default)]
-pub struct Options {
-    pub query: Vec<(String, String)>
+impl<I> Clone for Options<I> {
+    fn clone(&self) -> Options<I> {
+        Options {
+            query: self.query.clone(),
+            coercion: self.coercion,
+            strict_includes: self.strict_includes,
+            bypass_cache: self.bypass_cache,
+            errors: self.errors.clone(),
+            includes: PhantomData
+        }
+    }
 }
 
-impl Options {
-    pub fn empty() -> Options {
+impl<I> Options<I> {
+    pub fn empty() -> Options<I> {
         Options::default()
     }
 
-    pub fn builder() -> Options {
+    pub fn builder() -> Options<I> {
         Options::default()
     }
     
-    pub fn page(mut self, page: i64) -> Options {
-        self.query.push(("page".into(), page.to_string()));
+    pub fn page(self, page: i64) -> Options<I> {
+        self.set("page", page.to_string())
+    }
+
+    /// Results per page, between 1 and 150.
+    pub fn per_page(mut self, per_page: u32) -> Options<I> {
+        if per_page == 0 || per_page > MAX_PER_PAGE {
+            self.errors.push(format!("per_page must be between 1 and {}, got {}", MAX_PER_PAGE, per_page));
+        }
+        self.set("per_page", per_page.to_string())
+    }
+
+    /// Only returns data of the given leagues.
+    pub fn leagues(self, league_ids: &[i64]) -> Options<I> {
+        self.ids("leagues", league_ids)
+    }
+
+    /// Only returns odds of the given bookmakers.
+    pub fn bookmakers(self, bookmaker_ids: &[i64]) -> Options<I> {
+        self.ids("bookmakers", bookmaker_ids)
+    }
+
+    /// Only returns odds of the given markets.
+    pub fn markets(self, market_ids: &[i64]) -> Options<I> {
+        self.ids("markets", market_ids)
+    }
+
+    /// Only returns fixtures in one of the given statuses.
+    pub fn status(mut self, statuses: &[FixtureStatus]) -> Options<I> {
+        if statuses.is_empty() {
+            self.errors.push("status requires at least one status".to_string());
+        }
+        let statuses: Vec<&str> = statuses.iter().map(FixtureStatus::as_str).collect();
+        self.set("status", statuses.join(","))
+    }
+
+    /// Returns dates in the given IANA timezone (e.g. `Europe/Amsterdam`) instead of UTC.
+    pub fn timezone(mut self, timezone: &str) -> Options<I> {
+        if timezone.parse::<Tz>().is_err() {
+            self.errors.push(format!("unknown timezone {:?}", timezone));
+        }
+        self.set("tz", timezone.to_string())
+    }
+
+    pub fn current_page(&self) -> Option<i64> {
+        self.query.iter()
+            .find(|(name, _)| name == "page")
+            .and_then(|(_, value)| value.parse().ok())
+    }
+
+    /// Adds raw relations to the `include` parameter, e.g. `squad.player` or `lineup:limit(2|1)`.
+    pub fn include(self, include: &[&str]) -> Options<I> {
+        include.iter().fold(self, |options, include| options.add_include(include))
+    }
+
+    /// Adds a relation of the endpoint's resource to the `include` parameter.
+    pub fn with<P: Into<IncludePath<I>>>(self, include: P) -> Options<I> {
+        self.add_include(include.into().as_str())
+    }
+
+    pub fn param(mut self, name: &str, value: &str) -> Options<I> {
+        self.query.push((name.into(), value.into()));
         self
     }
 
-    pub fn include(mut self, include: &[&str]) -> Options {
-        let joined_includes = include.join(",");
-        self.query.push(("include".into(), joined_includes));
+    pub fn coercion(mut self, coercion: Coercion) -> Options<I> {
+        self.coercion = Some(coercion);
         self
     }
 
-    pub fn param(mut self, name: &str, value: &str) -> Options {
-        self.query.push((name.into(), value.into()));
+    pub fn strict_includes(mut self) -> Options<I> {
+        self.strict_includes = true;
         self
     }
+
+    /// Fetches a fresh response even if a cached one is available.
+    pub fn bypass_cache(mut self) -> Options<I> {
+        self.bypass_cache = true;
+        self
+    }
+
+    /// The include paths of the request, without modifiers such as `:limit(2|1)`.
+    pub fn requested_includes(&self) -> Vec<String> {
+        self.query.iter()
+            .filter(|(name, _)| name == "include")
+            .flat_map(|(_, value)| value.split(','))
+            .map(|include| include.split('.').map(|segment| segment.split(':').next().unwrap_or("").trim()).collect::<Vec<_>>().join("."))
+            .filter(|include| !include.is_empty())
+            .collect()
+    }
+
+    /// Problems found by the typed filters; a request with any of them is not sent.
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    fn ids(mut self, name: &str, ids: &[i64]) -> Options<I> {
+        if ids.is_empty() {
+            self.errors.push(format!("{} requires at least one id", name));
+        }
+        let ids: Vec<String> = ids.iter().map(ToString::to_string).collect();
+        self.set(name, ids.join(","))
+    }
+
+    /// Appends `include` to the single comma-separated `include` parameter, as the API only
+    /// reads one of them.
+    fn add_include(mut self, include: &str) -> Options<I> {
+        if include.is_empty() {
+            return self;
+        }
+        match self.query.iter_mut().find(|(name, _)| name == "include") {
+            Some(param) => {
+                param.1.push(',');
+                param.1.push_str(include);
+            },
+            None => self.query.push(("include".into(), include.to_string())),
+        }
+        self
+    }
+
+    fn set(mut self, name: &str, value: String) -> Options<I> {
+        match self.query.iter_mut().find(|(param, _)| param == name) {
+            Some(param) => param.1 = value,
+            None => self.query.push((name.into(), value)),
+        }
+        self
+    }
+
+    fn validate(&self) -> Result<(), SportMonksError> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(SportMonksError::InvalidOptions(self.errors.clone()))
+        }
+    }
+
+    /// Drops the include type, as the gateways expect.
+    pub fn untyped(self) -> Options {
+        Options {
+            query: self.query,
+            coercion: self.coercion,
+            strict_includes: self.strict_includes,
+            bypass_cache: self.bypass_cache,
+            errors: self.errors,
+            includes: PhantomData
+        }
+    }
 }
 
 impl Gateway {
     pub fn new(api_key: String) -> Gateway {
-        Gateway { http_client: Client::new(), api_key }
+        Gateway::with_client(Client::new(), api_key, DEFAULT_BASE_URL.into())
+    }
+
+    pub fn with_client(http_client: Client, api_key: String, base_url: String) -> Gateway {
+        Gateway::with_transport(Arc::new(http_client), api_key, base_url)
+    }
+
+    /// Sends every request through `transport` instead of a `reqwest::Client`.
+    pub fn with_transport(transport: Arc<dyn Transport>, api_key: String, base_url: String) -> Gateway {
+        Gateway {
+            shared: Arc::new(GatewayConfig {
+                transport,
+                api_key,
+                base_url,
+                retry_policy: RetryPolicy::none(),
+                rate_limiter: None,
+                coercion: Coercion::default(),
+                cache: None,
+                cassette: None,
+                middleware: middleware::Chain::default(),
+            })
+        }
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Gateway {
+        Arc::make_mut(&mut self.shared).retry_policy = retry_policy;
+        self
+    }
+
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Gateway {
+        Arc::make_mut(&mut self.shared).rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    pub fn coercion(mut self, coercion: Coercion) -> Gateway {
+        Arc::make_mut(&mut self.shared).coercion = coercion;
+        self
     }
 
-    pub fn get<U: DeserializeOwned>(&self, path: &str, options: Options) -> Result<U, SportMonksError> {
-        let url = format!("{}{}", BASE_URL, path);
-        let query_string = self.prepare_options(options);
-        let result = self.http_client
-            .get(url.as_str())
-            .query(&query_string)
-            .send();
-        self.handle_response(result)
+    /// Serves successful responses from `cache` for as long as `policy` allows.
+    pub fn cache(mut self, cache: Arc<dyn Cache>, policy: CachePolicy) -> Gateway {
+        Arc::make_mut(&mut self.shared).cache = Some(ResponseCache { backend: cache, policy });
+        self
     }
 
-    fn prepare_options(&self, options: Options) -> Vec<(String, String)> {
-        let mut query_string : Vec<(String, String)> = vec![("api_token".to_string(), self.api_key.to_string())];
-        query_string.extend(options.query);
-        query_string
+    /// Records responses to, or replays them from, `cassette`.
+    pub fn cassette(mut self, cassette: Arc<Cassette>) -> Gateway {
+        Arc::make_mut(&mut self.shared).cassette = Some(cassette);
+        self
     }
 
-    fn handle_response<U: DeserializeOwned>(&self, result: Result<Response, reqwest::Error>) -> Result<U,  SportMonksError> {
+    /// Adds `middleware` to the hooks run around every request.
+    pub fn middleware(mut self, middleware: Arc<dyn Middleware>) -> Gateway {
+        Arc::make_mut(&mut self.shared).middleware.push(middleware);
+        self
+    }
+
+    fn sink(&self) -> ResponseSink {
+        ResponseSink {
+            api_key: self.shared.api_key.clone(),
+            rate_limiter: self.shared.rate_limiter.clone(),
+            cache: self.shared.cache.clone(),
+            cassette: self.shared.cassette.clone(),
+        }
+    }
+
+    pub fn get<T: DeserializeOwned>(&self, path: &str, mut options: Options) -> Result<Wrapper<T>, SportMonksError> {
+        if self.shared.middleware.is_empty() {
+            return self.fetch(path, options).map(|(_, wrapper)| wrapper);
+        }
+        let started = Instant::now();
+        let mut request = RequestInfo { path: path.to_string(), query: options.query.clone(), tags: Vec::new() };
+        let synthetic = self.shared.middleware.before_request(&mut request);
+        options.query = request.query.clone();
+        let result = match synthetic {
+            Some(ref response) => parse_body(path, &Decoding::new(&options, self.shared.coercion), response.status, response.retry_after, &response.body)
+                .map(|wrapper| (response.status, wrapper)),
+            None => self.fetch(path, options),
+        };
         match result {
-            Ok(mut response) => {
-                if response.status().is_success() {
-                    match response.json::<U>() {
-                        Ok(final_response) => Ok(final_response),
-                        Err(error) => {
-                            Err(SportMonksError::new(0, error.to_string()))
-                        }
-                    }
-                } else {
-                    let parsed_result = response.json::<SportMonksError>();
-                    match parsed_result {
-                        Ok(sportmonks_error) => { Err(sportmonks_error) }, 
-                        Err(x) => {    
-                            Err(SportMonksError::new(0, x.to_string())) 
-                        }
+            Ok((status, wrapper)) => {
+                self.shared.middleware.after_response(&request, &ResponseInfo { status, elapsed: started.elapsed(), synthetic: synthetic.is_some() });
+                Ok(wrapper)
+            },
+            Err(error) => {
+                self.shared.middleware.on_error(&request, &self.shared.api_key, &error, started.elapsed());
+                Err(error)
+            }
+        }
+    }
+
+    /// Serves the request from the cache, the cassette or the API, along with the status.
+    fn fetch<T: DeserializeOwned>(&self, path: &str, options: Options) -> Result<(StatusCode, Wrapper<T>), SportMonksError> {
+        options.validate()?;
+        let url = format!("{}{}", self.shared.base_url, path);
+        let decoding = Decoding::new(&options, self.shared.coercion);
+        let key = cache::cache_key(path, &options.query);
+        if let Some(body) = cached(&self.shared.cache, &key, &options)? {
+            return parse_body(path, &decoding, StatusCode::OK, None, &body).map(|wrapper| (StatusCode::OK, wrapper));
+        }
+        if let Some(interaction) = replay(&self.shared.cassette, &key) {
+            let interaction = interaction?;
+            return self.sink().receive(path, &key, &decoding, interaction.status(), interaction.retry_after(), &interaction.body)
+                .map(|wrapper| (interaction.status(), wrapper));
+        }
+        let request = HttpRequest { url, query: prepare_options(&self.shared.api_key, options) };
+        let mut attempt = 1;
+        loop {
+            if let Some(ref rate_limiter) = self.shared.rate_limiter {
+                rate_limiter.acquire()?;
+            }
+            let result = self.shared.transport.get(&request);
+            let delay = match result {
+                Ok(ref response) => self.shared.retry_policy.next_delay(&Method::GET, attempt, Outcome::Status(response.status, response.retry_after)),
+                Err(SportMonksError::Transport(ref error)) | Err(SportMonksError::Timeout(ref error)) =>
+                    self.shared.retry_policy.next_delay(&Method::GET, attempt, Outcome::Failure(error)),
+                Err(SportMonksError::Unreachable(_)) => self.shared.retry_policy.next_delay(&Method::GET, attempt, Outcome::Unreachable),
+                Err(_) => None,
+            };
+            match delay {
+                Some(delay) => {
+                    thread::sleep(delay);
+                    attempt += 1;
+                },
+                None => return self.handle_response(path, &key, &decoding, result)
+            }
+        }
+    }
+
+    fn handle_response<T: DeserializeOwned>(&self, path: &str, key: &str, decoding: &Decoding, result: Result<HttpResponse, SportMonksError>) -> Result<(StatusCode, Wrapper<T>), SportMonksError> {
+        let response = result?;
+        self.sink().receive(path, key, decoding, response.status, response.retry_after, &response.body)
+            .map(|wrapper| (response.status, wrapper))
+    }
+}
+
+impl AsyncGateway {
+    pub fn new(api_key: String) -> AsyncGateway {
+        AsyncGateway::with_client(AsyncClient::new(), api_key, DEFAULT_BASE_URL.into())
+    }
+
+    pub fn with_client(http_client: AsyncClient, api_key: String, base_url: String) -> AsyncGateway {
+        AsyncGateway {
+            shared: Arc::new(AsyncGatewayConfig {
+                http_client,
+                api_key,
+                base_url,
+                retry_policy: RetryPolicy::none(),
+                rate_limiter: None,
+                coercion: Coercion::default(),
+                cache: None,
+                cassette: None,
+            })
+        }
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> AsyncGateway {
+        Arc::make_mut(&mut self.shared).retry_policy = retry_policy;
+        self
+    }
+
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> AsyncGateway {
+        Arc::make_mut(&mut self.shared).rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    pub fn coercion(mut self, coercion: Coercion) -> AsyncGateway {
+        Arc::make_mut(&mut self.shared).coercion = coercion;
+        self
+    }
+
+    /// Serves successful responses from `cache` for as long as `policy` allows.
+    pub fn cache(mut self, cache: Arc<dyn Cache>, policy: CachePolicy) -> AsyncGateway {
+        Arc::make_mut(&mut self.shared).cache = Some(ResponseCache { backend: cache, policy });
+        self
+    }
+
+    /// Records responses to, or replays them from, `cassette`.
+    pub fn cassette(mut self, cassette: Arc<Cassette>) -> AsyncGateway {
+        Arc::make_mut(&mut self.shared).cassette = Some(cassette);
+        self
+    }
+
+    fn sink(&self) -> ResponseSink {
+        ResponseSink {
+            api_key: self.shared.api_key.clone(),
+            rate_limiter: self.shared.rate_limiter.clone(),
+            cache: self.shared.cache.clone(),
+            cassette: self.shared.cassette.clone(),
+        }
+    }
+
+    pub fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str, options: Options) -> SportMonksFuture<Wrapper<T>> {
+        if let Err(error) = options.validate() {
+            return Box::new(future::err(error));
+        }
+        let url = format!("{}{}", self.shared.base_url, path);
+        let decoding = Decoding::new(&options, self.shared.coercion);
+        let key = cache::cache_key(path, &options.query);
+        match cached(&self.shared.cache, &key, &options) {
+            Ok(Some(body)) => return Box::new(future::result(parse_body(path, &decoding, StatusCode::OK, None, &body))),
+            Err(error) => return Box::new(future::err(error)),
+            Ok(None) => (),
+        }
+        let sink = self.sink();
+        if let Some(interaction) = replay(&self.shared.cassette, &key) {
+            return Box::new(future::result(interaction.and_then(|interaction| {
+                sink.receive(path, &key, &decoding, interaction.status(), interaction.retry_after(), &interaction.body)
+            })));
+        }
+        let query_string = prepare_options(&self.shared.api_key, options);
+        let http_client = self.shared.http_client.clone();
+        let retry_policy = self.shared.retry_policy.clone();
+        let rate_limiter = self.shared.rate_limiter.clone();
+        let endpoint = path.to_string();
+        let future = future::loop_fn(1, move |attempt| {
+            let retry_policy = retry_policy.clone();
+            let request = http_client
+                .get(url.as_str())
+                .query(&query_string);
+            acquire_async(rate_limiter.clone())
+                .and_then(move |_| request.send().then(move |result| {
+                    let delay = {
+                        let outcome = match result {
+                            Ok(ref response) => Outcome::Status(response.status(), transport::retry_after(response.headers())),
+                            Err(ref error) => Outcome::Failure(error),
+                        };
+                        retry_policy.next_delay(&Method::GET, attempt, outcome)
+                    };
+                    match delay {
+                        Some(delay) => Either::A(Delay::new(Instant::now() + delay)
+                            .map_err(SportMonksError::from)
+                            .map(move |_| Loop::Continue(attempt + 1))),
+                        None => Either::B(future::ok(Loop::Break(result)))
                     }
-                }
+                }))
+        }).and_then(move |result| match result {
+            Ok(response) => Either::A(handle_async_response(endpoint, key, decoding, response, sink)),
+            Err(error) => Either::B(future::err(SportMonksError::from(error)))
+        });
+        Box::new(future)
+    }
+}
+
+fn handle_async_response<T: DeserializeOwned>(endpoint: String, key: String, decoding: Decoding, mut response: AsyncResponse, sink: ResponseSink) -> impl Future<Item = Wrapper<T>, Error = SportMonksError> {
+    let status = response.status();
+    let retry_after = transport::retry_after(response.headers());
+    response.text()
+        .map_err(SportMonksError::from)
+        .and_then(move |body| {
+            sink.receive(&endpoint, &key, &decoding, status, retry_after, &body)
+        })
+}
+
+fn acquire_async(rate_limiter: Option<Arc<RateLimiter>>) -> Box<dyn Future<Item = (), Error = SportMonksError> + Send> {
+    match rate_limiter {
+        None => Box::new(future::ok(())),
+        Some(rate_limiter) => Box::new(future::loop_fn((), move |_| match rate_limiter.try_acquire() {
+            Ok(()) => Either::A(future::ok(Loop::Break(()))),
+            Err(wait) => match rate_limiter.exhaustion() {
+                Exhaustion::Block => Either::B(Either::A(Delay::new(Instant::now() + wait)
+                    .map_err(SportMonksError::from)
+                    .map(Loop::Continue))),
+                Exhaustion::Error => Either::B(Either::B(future::err(exhausted(wait)))),
+            }
+        }))
+    }
+}
+
+/// Where a response body goes once read: the rate limiter, the cassette and the cache.
+struct ResponseSink {
+    api_key: String,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    cache: Option<ResponseCache>,
+    cassette: Option<Arc<Cassette>>,
+}
+
+impl ResponseSink {
+    /// Parses the body and caches it if it decoded into a successful response.
+    fn receive<T: DeserializeOwned>(&self, endpoint: &str, key: &str, decoding: &Decoding, status: StatusCode, retry_after: Option<Duration>, body: &str) -> Result<Wrapper<T>, SportMonksError> {
+        if let Some(ref rate_limiter) = self.rate_limiter {
+            rate_limiter.observe(status.as_u16(), body);
+        }
+        if let Some(ref cassette) = self.cassette {
+            if cassette.mode() == CassetteMode::Record {
+                cassette.record_response(key, &self.api_key, status, retry_after, body);
+            }
+        }
+        let parsed = parse_body(endpoint, decoding, status, retry_after, body);
+        if let (Some(cache), true) = (self.cache.as_ref(), parsed.is_ok()) {
+            cache.put(endpoint, key, body);
+        }
+        parsed
+    }
+}
+
+/// The next recorded response to `key`, when replaying a cassette.
+fn replay(cassette: &Option<Arc<Cassette>>, key: &str) -> Option<Result<cassette::Interaction, SportMonksError>> {
+    match *cassette {
+        Some(ref cassette) if cassette.mode() == CassetteMode::Replay => Some(cassette.play(key)),
+        _ => None,
+    }
+}
+
+/// The cached body of `key`, if any; offline, a missing body is an error.
+fn cached(cache: &Option<ResponseCache>, key: &str, options: &Options) -> Result<Option<String>, SportMonksError> {
+    match *cache {
+        Some(ref cache) if cache.policy.is_offline() =>
+            cache.get(key).map(Some).ok_or_else(|| SportMonksError::NotCached { key: key.to_string() }),
+        Some(ref cache) if !options.bypass_cache => Ok(cache.get(key)),
+        _ => Ok(None),
+    }
+}
+
+/// How a successful response body is checked and decoded.
+#[derive(Clone)]
+struct Decoding {
+    coercion: Coercion,
+    includes: Vec<String>,
+    strict_includes: bool,
+}
+
+impl Decoding {
+    fn new(options: &Options, default_coercion: Coercion) -> Decoding {
+        Decoding {
+            coercion: options.coercion.unwrap_or(default_coercion),
+            includes: options.requested_includes(),
+            strict_includes: options.strict_includes,
+        }
+    }
+}
+
+fn prepare_options(api_key: &str, options: Options) -> Vec<(String, String)> {
+    let mut query_string : Vec<(String, String)> = vec![("api_token".to_string(), api_key.to_string())];
+    query_string.extend(options.query);
+    query_string
+}
+
+fn parse_body<T: DeserializeOwned>(endpoint: &str, decoding: &Decoding, status: StatusCode, retry_after: Option<Duration>, body: &str) -> Result<Wrapper<T>, SportMonksError> {
+    if status.is_success() {
+        let (decoded, warnings) = coercion::with_mode(decoding.coercion, || decode::<Wrapper<T>>(endpoint, body));
+        let mut wrapper = decoded?;
+        wrapper.warnings = warnings;
+        if !decoding.includes.is_empty() {
+            let payload = decode(endpoint, body)?;
+            wrapper.includes = IncludeReport::new(&decoding.includes, &payload);
+            if decoding.strict_includes && !wrapper.includes.is_complete() {
+                return Err(SportMonksError::MissingIncludes { endpoint: endpoint.to_string(), missing: wrapper.includes.missing.clone() });
+            }
+        }
+        Ok(wrapper)
+    } else {
+        Err(SportMonksError::from_response(status, retry_after, body))
+    }
+}
+
+fn decode<U: DeserializeOwned>(endpoint: &str, body: &str) -> Result<U, SportMonksError> {
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+    serde_path_to_error::deserialize(deserializer).map_err(|error| {
+        let path = error.path().to_string();
+        let source = error.into_inner();
+        SportMonksError::Deserialize {
+            endpoint: endpoint.to_string(),
+            path,
+            snippet: snippet(body, source.line(), source.column()),
+            source,
+        }
+    })
+}
+
+/// The text around a serde_json error position, truncated to keep error messages readable.
+fn snippet(body: &str, line: usize, column: usize) -> String {
+    let line_start: usize = body.split('\n').take(line.saturating_sub(1)).map(|line| line.len() + 1).sum();
+    let position = cmp::min(line_start + column, body.len());
+    let mut start = position.saturating_sub(SNIPPET_RADIUS);
+    while !body.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = cmp::min(position + SNIPPET_RADIUS, body.len());
+    while !body.is_char_boundary(end) {
+        end += 1;
+    }
+    body[start..end].split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use models::{Wrapper, League, Goals};
+
+    #[test]
+    fn it_reports_the_json_path_of_decode_failures() {
+        let body = r#"{"data": [{"id": 1, "stats": {"passing": {"passes": 12}}}, {"id": 2, "stats": {"passing": {"passes": "N/A"}}}]}"#;
+
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct Player { stats: Stats }
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct Stats { passing: Passing }
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct Passing { passes: i64 }
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct Lineup { data: Vec<Player> }
+
+        match decode::<Lineup>("/fixtures/42", body).unwrap_err() {
+            SportMonksError::Deserialize { endpoint, path, snippet, .. } => {
+                assert_eq!(endpoint, "/fixtures/42");
+                assert_eq!(path, "data[1].stats.passing.passes");
+                assert!(snippet.contains("\"N/A\""));
             },
-            Err(error) => Err(SportMonksError::new(0, error.description().to_string()))
+            other => panic!("unexpected error {:?}", other)
         }
     }
-}
\ No newline at end of file
+
+    fn decoding(coercion: Coercion) -> Decoding {
+        Decoding::new(&Options::empty(), coercion)
+    }
+
+    #[test]
+    fn it_attaches_coercion_warnings_to_the_wrapper() {
+        let body = r#"{"data": {"position": 1, "season_id": 16, "player_id": 7, "team_id": 9, "goals": "12.5%", "penalty_goals": 0, "type": "goals"}}"#;
+
+        let wrapper = parse_body::<Goals>("/topscorers/season/16", &decoding(Coercion::Lenient), StatusCode::OK, None, body).unwrap();
+        assert_eq!(wrapper.data.goals, 0);
+        assert_eq!(wrapper.warnings.len(), 1);
+
+        match parse_body::<Goals>("/topscorers/season/16", &decoding(Coercion::Strict), StatusCode::OK, None, body).unwrap_err() {
+            SportMonksError::Deserialize { path, .. } => assert_eq!(path, "data.goals"),
+            other => panic!("unexpected error {:?}", other)
+        }
+    }
+
+    #[test]
+    fn it_truncates_snippets_of_large_payloads() {
+        let body = format!(r#"{{"data": {{"id": "x", "name": "{}"}}}}"#, "a".repeat(10_000));
+
+        match decode::<Wrapper<League>>("/leagues/8", &body).unwrap_err() {
+            SportMonksError::Deserialize { path, snippet, .. } => {
+                assert_eq!(path, "data.id");
+                assert!(snippet.len() <= 2 * SNIPPET_RADIUS);
+            },
+            other => panic!("unexpected error {:?}", other)
+        }
+    }
+}
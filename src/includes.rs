@@ -0,0 +1,297 @@
+use serde_json::Value;
+use std::marker::PhantomData;
+
+/// A relation that can be requested through the `include` query parameter.
+pub trait Include {
+    fn name(&self) -> &'static str;
+
+    /// Follows this relation with one of the related resource's own relations, e.g. `squad.player`.
+    fn then<J: Include>(self, nested: J) -> IncludePath<Self> where Self: Sized {
+        IncludePath::from(self).then(nested)
+    }
+}
+
+/// A possibly nested include, rooted at a relation of the resource `I`.
+///
+/// Only the first segment is checked against the endpoint; nested segments are taken as given.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncludePath<I> {
+    path: String,
+    root: PhantomData<fn() -> I>,
+}
+
+impl<I> IncludePath<I> {
+    pub fn then<J: Include>(mut self, nested: J) -> IncludePath<I> {
+        self.path.push('.');
+        self.path.push_str(nested.name());
+        self
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.path
+    }
+}
+
+impl<I: Include> From<I> for IncludePath<I> {
+    fn from(include: I) -> IncludePath<I> {
+        IncludePath { path: include.name().to_string(), root: PhantomData }
+    }
+}
+
+/// Which of the requested includes came back in a response.
+///
+/// An include counts as present when every record of `data` carries it, following nested
+/// includes through the `{"data": ...}` wrappers. The plan lacking access to a relation shows
+/// up as a missing include rather than as a silent `None`. Includes that no record could
+/// carry, as with an empty `data` array, are reported as unknown.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct IncludeReport {
+    pub present: Vec<String>,
+    pub missing: Vec<String>,
+    pub unknown: Vec<String>,
+}
+
+impl IncludeReport {
+    pub fn new(requested: &[String], body: &Value) -> IncludeReport {
+        let data = &body["data"];
+        let mut report = IncludeReport::default();
+        for include in requested {
+            match is_present(data, &include.split('.').collect::<Vec<_>>()) {
+                Some(true) => report.present.push(include.clone()),
+                Some(false) => report.missing.push(include.clone()),
+                None => report.unknown.push(include.clone()),
+            }
+        }
+        report
+    }
+
+    pub fn is_present(&self, include: &str) -> bool {
+        self.present.iter().any(|present| present == include)
+    }
+
+    /// Whether no requested include is known to be missing.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// `None` when there is no record to check, such as in an empty array.
+fn is_present(value: &Value, segments: &[&str]) -> Option<bool> {
+    if let Value::Array(ref values) = *value {
+        return values.iter()
+            .filter_map(|value| is_present(value, segments))
+            .fold(None, |all, present| Some(all.unwrap_or(true) && present));
+    }
+    match segments.split_first() {
+        None => Some(true),
+        Some((segment, rest)) => match value.get(segment) {
+            None | Some(&Value::Null) => Some(false),
+            Some(relation) => is_present(relation.get("data").unwrap_or(relation), rest),
+        }
+    }
+}
+
+macro_rules! includes {
+    ($(#[$attr:meta])* $name:ident { $($variant:ident => $value:expr,)* }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl Include for $name {
+            fn name(&self) -> &'static str {
+                match *self {
+                    $($name::$variant => $value,)*
+                }
+            }
+        }
+    };
+}
+
+includes!(
+    /// Relations of `Continent`, accepted by the continent endpoints.
+    ContinentInclude {
+        Countries => "countries",
+    }
+);
+
+includes!(
+    /// Relations of `Country`, accepted by the country endpoints.
+    CountryInclude {
+        Leagues => "leagues",
+    }
+);
+
+includes!(
+    /// Relations of `League`, accepted by the league endpoints.
+    LeagueInclude {
+        Country => "country",
+        Season => "season",
+        Seasons => "seasons",
+    }
+);
+
+includes!(
+    /// Relations of `Season`, accepted by the season and topscorer endpoints.
+    SeasonInclude {
+        Stages => "stages",
+        Rounds => "rounds",
+        Upcoming => "upcoming",
+        Results => "results",
+        Groups => "groups",
+        Goalscorers => "goalscorers",
+        Assistscorers => "assistscorers",
+        Cardscorers => "cardscorers",
+        AggregatedGoalscorers => "aggregatedGoalscorers",
+        AggregatedAssistscorers => "aggregatedAssistscorers",
+        AggregatedCardscorers => "aggregatedCardscorers",
+    }
+);
+
+includes!(
+    /// Relations of `Fixture`, accepted by the fixture, livescore and head to head endpoints.
+    FixtureInclude {
+        LocalTeam => "localTeam",
+        VisitorTeam => "visitorTeam",
+        Substitutions => "substitutions",
+        Goals => "goals",
+        Cards => "cards",
+        Events => "events",
+        Stage => "stage",
+        Referee => "referee",
+        Venue => "venue",
+        Odds => "odds",
+        InPlay => "inplay",
+        FlatOdds => "flatOdds",
+        LocalCoach => "localCoach",
+        VisitorCoach => "visitorCoach",
+        Trends => "trends",
+        FirstAssistant => "firstAssistant",
+        SecondAssistant => "secondAssistant",
+        FourthOfficial => "fourthOfficial",
+        Corners => "corners",
+        Lineup => "lineup",
+        Bench => "bench",
+        Sidelined => "sidelined",
+        Comments => "comments",
+        TvStations => "tvstations",
+        Highlights => "highlights",
+        Round => "round",
+        Group => "group",
+        Stats => "stats",
+    }
+);
+
+includes!(
+    /// Relations of `Team`, accepted by the team endpoints.
+    TeamInclude {
+        Country => "country",
+        Squad => "squad",
+        Coach => "coach",
+        Transfers => "transfers",
+        Sidelined => "sidelined",
+        Stats => "stats",
+        Venue => "venue",
+        UefaRanking => "uefaranking",
+        VisitorFixtures => "visitorFixtures",
+        LocalFixtures => "localFixtures",
+        VisitorResults => "visitorResults",
+        Latest => "latest",
+        Upcoming => "upcoming",
+        Goalscorers => "goalscorers",
+        Cardscorers => "cardscorers",
+        Assistscorers => "assistscorers",
+        AggregatedGoalscorers => "aggregatedGoalscorers",
+        AggregatedCardscorers => "aggregatedCardscorers",
+        AggregatedAssistscorers => "aggregatedAssistscorers",
+    }
+);
+
+includes!(
+    /// Relations of `Player`, accepted by the player endpoints.
+    PlayerInclude {
+        Position => "position",
+        Trophies => "trophies",
+        Transfers => "transfers",
+        Sidelined => "sidelined",
+    }
+);
+
+includes!(
+    /// Relations of `Standing`, accepted by the standing endpoints.
+    StandingInclude {
+        Standings => "standings",
+    }
+);
+
+includes!(
+    /// Relations of `StandingPosition`, to be nested under `StandingInclude::Standings`.
+    StandingPositionInclude {
+        Team => "team",
+    }
+);
+
+includes!(
+    /// Relations of `PlayerInMatch`, to be nested under `TeamInclude::Squad`.
+    PlayerInMatchInclude {
+        Player => "player",
+    }
+);
+
+includes!(
+    /// Relations of `Odds`, to be nested under `FixtureInclude::Odds`.
+    OddsInclude {
+        Bookmaker => "bookmaker",
+    }
+);
+
+includes!(
+    /// Relations of `Trophy`, to be nested under `PlayerInclude::Trophies`.
+    TrophyInclude {
+        Seasons => "seasons",
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn it_reports_requested_includes_missing_from_the_payload() {
+        let body: Value = serde_json::from_str(r#"{"data": [
+            {"id": 1, "localTeam": {"data": {"id": 9}}, "lineup": {"data": [{"player": {"data": {"id": 3}}}]}},
+            {"id": 2, "localTeam": {"data": {"id": 7}}, "lineup": {"data": []}, "odds": null}
+        ]}"#).unwrap();
+        let requested = vec!["localTeam".to_string(), "lineup.player".to_string(), "odds".to_string(), "venue".to_string()];
+
+        let report = IncludeReport::new(&requested, &body);
+
+        assert_eq!(report.present, vec!["localTeam".to_string(), "lineup.player".to_string()]);
+        assert_eq!(report.missing, vec!["odds".to_string(), "venue".to_string()]);
+        assert!(report.is_present("localTeam"));
+        assert!(!report.is_complete());
+    }
+
+    #[test]
+    fn it_cannot_tell_the_includes_of_an_empty_list() {
+        let body: Value = serde_json::from_str(r#"{"data": []}"#).unwrap();
+        let requested = vec!["localTeam".to_string(), "lineup.player".to_string()];
+
+        let report = IncludeReport::new(&requested, &body);
+
+        assert!(report.present.is_empty());
+        assert!(report.missing.is_empty());
+        assert_eq!(report.unknown, requested);
+        assert!(report.is_complete());
+    }
+
+    #[test]
+    fn it_builds_nested_include_paths() {
+        let path = TeamInclude::Squad.then(PlayerInMatchInclude::Player).then(PlayerInclude::Position);
+
+        assert_eq!(path.as_str(), "squad.player.position");
+        assert_eq!(IncludePath::from(FixtureInclude::LocalTeam).as_str(), "localTeam");
+    }
+}
@@ -5,17 +5,55 @@ extern crate serde_derive;
 extern crate reqwest;
 #[cfg(test)]
 extern crate mockito;
+#[cfg(test)]
+extern crate tokio;
 extern crate chrono;
+extern crate chrono_tz;
+extern crate futures;
+extern crate rand;
+extern crate tokio_timer;
+extern crate serde_path_to_error;
 
 pub mod ops;
 pub mod models;
 pub mod errors;
 pub mod gateway;
+pub mod builder;
+pub mod retry;
+pub mod rate_limit;
+pub mod coercion;
+pub mod pagination;
+pub mod includes;
+pub mod timeline;
+pub mod live;
+pub mod cache;
+pub mod cassette;
+pub mod transport;
+pub mod middleware;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
 
-use gateway::{Gateway};
+use gateway::{Gateway,AsyncGateway};
+use errors::SportMonksError;
+use futures::Future;
 pub use self::models::*;
-pub use self::gateway::Options;
+pub use self::gateway::{Options,SportMonksFuture};
+pub use self::builder::ClientBuilder;
+pub use self::retry::RetryPolicy;
+pub use self::rate_limit::{RateLimiter, Exhaustion};
+pub use self::coercion::{Coercion, CoercionWarning};
+pub use self::pagination::{Pages, Items, PageStream};
+pub use self::includes::*;
+pub use self::timeline::{TimelineEntry, TimelineKind, Score};
+pub use self::live::{LiveWatcher, LiveEvent, LiveFeed};
+pub use self::cache::{Cache, CachePolicy, LruCache, FileCache};
+pub use self::cassette::{Cassette, CassetteMode};
+pub use self::transport::{Transport, HttpRequest, HttpResponse};
+pub use self::middleware::{Middleware, RequestInfo, ResponseInfo, FailureInfo};
 
+/// The blocking client. All its gateways share one `Gateway`, so cloning the client is cheap
+/// and the clones share the connection pool, rate limiter and cache.
+#[derive(Clone)]
 pub struct Client {
     pub contients: ops::ContinentGateway,
     pub commentaries: ops::CommentariesGateway,
@@ -39,20 +77,141 @@ impl std::fmt::Debug for Client {
 
 impl Client {
     pub fn new<S: Into<String>>(api_key: S) -> Client {
-        let api_key_string = api_key.into();
+        Client::with_gateway(Gateway::new(api_key.into()))
+    }
+
+    pub fn builder<S: Into<String>>(api_key: S) -> ClientBuilder {
+        ClientBuilder::new(api_key)
+    }
+
+    /// Plan, subscription and sports of the API token, read from the meta of a cheap request.
+    pub fn account_status(&self) -> Result<Meta, SportMonksError> {
+        self.contients.all().map(|response| response.meta.unwrap_or_default())
+    }
+
+    pub fn with_gateway(gateway: Gateway) -> Client {
         Client {
-            contients: ops::ContinentGateway::new(Gateway::new(api_key_string.clone())),
-            commentaries: ops::CommentariesGateway::new(Gateway::new(api_key_string.clone())),
-            countries: ops::CountryGateway::new(Gateway::new(api_key_string.clone())),
-            fixtures: ops::FixtureGateway::new(Gateway::new(api_key_string.clone())),
-            head_to_head: ops::HeadToHeadGateway::new(Gateway::new(api_key_string.clone())),
-            leagues: ops::LeagueGateway::new(Gateway::new(api_key_string.clone())),
-            livescores: ops::LivescoreGateway::new(Gateway::new(api_key_string.clone())),
-            players: ops::PlayerGateway::new(Gateway::new(api_key_string.clone())),
-            seasons: ops::SeasonGateway::new(Gateway::new(api_key_string.clone())),
-            standings: ops::StandingGateway::new(Gateway::new(api_key_string.clone())),
-            teams: ops::TeamGateway::new(Gateway::new(api_key_string.clone())),
-            topscorers: ops::TopscorerGateway::new(Gateway::new(api_key_string.clone())),
+            contients: ops::ContinentGateway::new(gateway.clone()),
+            commentaries: ops::CommentariesGateway::new(gateway.clone()),
+            countries: ops::CountryGateway::new(gateway.clone()),
+            fixtures: ops::FixtureGateway::new(gateway.clone()),
+            head_to_head: ops::HeadToHeadGateway::new(gateway.clone()),
+            leagues: ops::LeagueGateway::new(gateway.clone()),
+            livescores: ops::LivescoreGateway::new(gateway.clone()),
+            players: ops::PlayerGateway::new(gateway.clone()),
+            seasons: ops::SeasonGateway::new(gateway.clone()),
+            standings: ops::StandingGateway::new(gateway.clone()),
+            teams: ops::TeamGateway::new(gateway.clone()),
+            topscorers: ops::TopscorerGateway::new(gateway),
+        }
+    }
+}
+
+/// The asynchronous client, sharing one `AsyncGateway` between its gateways like `Client`.
+#[derive(Clone)]
+pub struct AsyncClient {
+    pub contients: ops::AsyncContinentGateway,
+    pub commentaries: ops::AsyncCommentariesGateway,
+    pub countries: ops::AsyncCountryGateway,
+    pub fixtures: ops::AsyncFixtureGateway,
+    pub head_to_head: ops::AsyncHeadToHeadGateway,
+    pub leagues: ops::AsyncLeagueGateway,
+    pub livescores: ops::AsyncLivescoreGateway,
+    pub players: ops::AsyncPlayerGateway,
+    pub seasons: ops::AsyncSeasonGateway,
+    pub standings: ops::AsyncStandingGateway,
+    pub teams: ops::AsyncTeamGateway,
+    pub topscorers: ops::AsyncTopscorerGateway,
+}
+
+impl std::fmt::Debug for AsyncClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "SportsMonks::AsyncClient")
+    }
+}
+
+impl AsyncClient {
+    pub fn new<S: Into<String>>(api_key: S) -> AsyncClient {
+        AsyncClient::with_gateway(AsyncGateway::new(api_key.into()))
+    }
+
+    pub fn builder<S: Into<String>>(api_key: S) -> ClientBuilder {
+        ClientBuilder::new(api_key)
+    }
+
+    /// Plan, subscription and sports of the API token, read from the meta of a cheap request.
+    pub fn account_status(&self) -> SportMonksFuture<Meta> {
+        Box::new(self.contients.all().map(|response| response.meta.unwrap_or_default()))
+    }
+
+    pub fn with_gateway(gateway: AsyncGateway) -> AsyncClient {
+        AsyncClient {
+            contients: ops::AsyncContinentGateway::new(gateway.clone()),
+            commentaries: ops::AsyncCommentariesGateway::new(gateway.clone()),
+            countries: ops::AsyncCountryGateway::new(gateway.clone()),
+            fixtures: ops::AsyncFixtureGateway::new(gateway.clone()),
+            head_to_head: ops::AsyncHeadToHeadGateway::new(gateway.clone()),
+            leagues: ops::AsyncLeagueGateway::new(gateway.clone()),
+            livescores: ops::AsyncLivescoreGateway::new(gateway.clone()),
+            players: ops::AsyncPlayerGateway::new(gateway.clone()),
+            seasons: ops::AsyncSeasonGateway::new(gateway.clone()),
+            standings: ops::AsyncStandingGateway::new(gateway.clone()),
+            teams: ops::AsyncTeamGateway::new(gateway.clone()),
+            topscorers: ops::AsyncTopscorerGateway::new(gateway),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+    use mockito::mock;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn it_reports_the_account_status() {
+        let body = fs::read_to_string(Path::new("src/support/continents/all.json")).expect("Fixtures:");
+        let m = mock("GET", "/continents?api_token=1234")
+          .with_status(200)
+          .with_body(body)
+          .create();
+
+        let status = Client::new("1234").account_status().unwrap();
+        m.assert();
+
+        let plan = status.plan.unwrap();
+        assert_eq!(plan.price, 20.0);
+        assert_eq!(plan.request_limit, RequestLimit { requests: 2000, minutes: 60 });
+        assert_eq!(status.sports[0].name, "Soccer");
+
+        let subscription = status.subscription.unwrap();
+        let trial_ends_at = Utc.with_ymd_and_hms(2019, 2, 26, 0, 11, 9).unwrap();
+        assert_eq!(subscription.trial_ends_at, Some(trial_ends_at));
+        assert_eq!(subscription.ends_at, None);
+        assert!(subscription.is_trial(trial_ends_at - Duration::days(3)));
+        assert!(subscription.expires_within(trial_ends_at - Duration::days(3), Duration::days(7)));
+        assert!(!subscription.expires_within(trial_ends_at + Duration::days(3), Duration::days(7)));
+    }
+
+    #[test]
+    fn it_shares_one_gateway_between_clones_and_threads() {
+        fn shareable<T: Clone + Send + Sync>() {}
+        shareable::<Client>();
+        shareable::<AsyncClient>();
+
+        let server = test_util::FakeServer::start();
+        let client = Client::builder("1234")
+            .base_url(server.url())
+            .cache_policy(CachePolicy::default())
+            .build()
+            .unwrap();
+        let clone = client.clone();
+
+        let leagues = std::thread::spawn(move || clone.leagues.all().unwrap().data).join().unwrap();
+
+        assert_eq!(client.leagues.all().unwrap().data, leagues);
+        assert_eq!(server.requests().len(), 1);
+    }
+}
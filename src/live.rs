@@ -0,0 +1,310 @@
+use super::errors::SportMonksError;
+use super::gateway::Options;
+use super::includes::FixtureInclude;
+use super::models::{Fixture, FixtureStatus, Side};
+use super::ops::LivescoreGateway;
+use super::timeline::Score;
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// A change noticed between two livescore snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiveEvent {
+    Kickoff { fixture_id: i64 },
+    Goal { fixture_id: i64, side: Side, score: Score },
+    /// A score went down, usually a disallowed goal.
+    ScoreCorrection { fixture_id: i64, previous: Score, score: Score },
+    /// Only reported when the `cards` include is requested.
+    RedCard { fixture_id: i64, side: Option<Side>, player_name: String },
+    StatusChange { fixture_id: i64, from: FixtureStatus, to: FixtureStatus },
+    FullTime { fixture_id: i64, score: Score },
+}
+
+/// Polls `/livescores/now` and reports what changed since the previous poll.
+///
+/// The first poll only records a baseline. Fixtures absent from the previous snapshot report a
+/// kickoff if they are already live, and nothing else.
+pub struct LiveWatcher {
+    livescores: LivescoreGateway,
+    options: Options<FixtureInclude>,
+    interval: Duration,
+    previous: Option<Vec<Fixture>>,
+}
+
+impl LiveWatcher {
+    pub fn new(livescores: LivescoreGateway, options: Options<FixtureInclude>) -> LiveWatcher {
+        LiveWatcher { livescores, options, interval: DEFAULT_INTERVAL, previous: None }
+    }
+
+    /// Time between two polls, 15 seconds by default.
+    pub fn interval(mut self, interval: Duration) -> LiveWatcher {
+        self.interval = interval;
+        self
+    }
+
+    /// Fetches a snapshot and returns the changes since the previous one.
+    pub fn poll(&mut self) -> Result<Vec<LiveEvent>, SportMonksError> {
+        let current = self.livescores.now_with(self.options.clone())?.data;
+        let events = match self.previous {
+            Some(ref previous) => diff(previous, &current),
+            None => Vec::new(),
+        };
+        self.previous = Some(current);
+        Ok(events)
+    }
+
+    /// Polls every interval, handing each event and each failed poll to `callback` until it
+    /// returns `false`.
+    pub fn run<F>(self, callback: F) where F: FnMut(Result<LiveEvent, SportMonksError>) -> bool {
+        self.run_while(|| true, callback)
+    }
+
+    /// Runs the watcher on its own thread. It stops before the next poll once the feed is dropped.
+    pub fn spawn(self) -> (LiveFeed, JoinHandle<()>) {
+        let (sender, receiver) = mpsc::channel();
+        let dropped = Arc::new(AtomicBool::new(false));
+        let feed = LiveFeed { receiver, dropped: dropped.clone() };
+        let handle = thread::spawn(move || {
+            self.run_while(|| !dropped.load(Ordering::SeqCst), |event| sender.send(event).is_ok())
+        });
+        (feed, handle)
+    }
+
+    fn run_while<S, F>(mut self, running: S, mut callback: F)
+        where S: Fn() -> bool, F: FnMut(Result<LiveEvent, SportMonksError>) -> bool {
+        while running() {
+            let keep_going = match self.poll() {
+                Ok(events) => events.into_iter().all(|event| callback(Ok(event))),
+                Err(error) => callback(Err(error)),
+            };
+            if !keep_going {
+                return;
+            }
+            thread::sleep(self.interval);
+        }
+    }
+}
+
+/// The events of a spawned `LiveWatcher`. Dropping it stops the watcher.
+///
+/// Iterating blocks until the next event; the `Receiver` methods such as `try_recv` are
+/// available as well.
+pub struct LiveFeed {
+    receiver: Receiver<Result<LiveEvent, SportMonksError>>,
+    dropped: Arc<AtomicBool>,
+}
+
+impl Deref for LiveFeed {
+    type Target = Receiver<Result<LiveEvent, SportMonksError>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.receiver
+    }
+}
+
+impl Iterator for LiveFeed {
+    type Item = Result<LiveEvent, SportMonksError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok()
+    }
+}
+
+impl Drop for LiveFeed {
+    fn drop(&mut self) {
+        self.dropped.store(true, Ordering::SeqCst);
+    }
+}
+
+/// The events leading from the `previous` snapshot to the `current` one.
+pub fn diff(previous: &[Fixture], current: &[Fixture]) -> Vec<LiveEvent> {
+    let previous: HashMap<i64, &Fixture> = previous.iter().map(|fixture| (fixture.id, fixture)).collect();
+    let mut events = Vec::new();
+    for fixture in current {
+        match previous.get(&fixture.id) {
+            Some(before) => diff_fixture(before, fixture, &mut events),
+            None if fixture.is_live() => events.push(LiveEvent::Kickoff { fixture_id: fixture.id }),
+            None => (),
+        }
+    }
+    events
+}
+
+fn diff_fixture(before: &Fixture, after: &Fixture, events: &mut Vec<LiveEvent>) {
+    let fixture_id = after.id;
+    let (from, to) = (before.status(), after.status());
+    if from != to {
+        events.push(LiveEvent::StatusChange { fixture_id, from: from.clone(), to: to.clone() });
+        if !from.is_live() && !from.is_finished() && to.is_live() {
+            events.push(LiveEvent::Kickoff { fixture_id });
+        }
+    }
+
+    let (previous, score) = (score_of(before), score_of(after));
+    if score.home < previous.home || score.away < previous.away {
+        events.push(LiveEvent::ScoreCorrection { fixture_id, previous, score });
+    } else {
+        let mut running = previous;
+        while running.home < score.home {
+            running.home += 1;
+            events.push(LiveEvent::Goal { fixture_id, side: Side::Home, score: running });
+        }
+        while running.away < score.away {
+            running.away += 1;
+            events.push(LiveEvent::Goal { fixture_id, side: Side::Away, score: running });
+        }
+    }
+
+    let booked: Vec<i64> = before.cards.iter().flatten().map(|card| card.id).collect();
+    for card in after.cards.iter().flatten() {
+        if (card.kind == "redcard" || card.kind == "yellowred") && !booked.contains(&card.id) {
+            events.push(LiveEvent::RedCard { fixture_id, side: after.side_of(card.team_id), player_name: card.player_name.clone() });
+        }
+    }
+
+    if to.is_finished() && !from.is_finished() {
+        events.push(LiveEvent::FullTime { fixture_id, score });
+    }
+}
+
+fn score_of(fixture: &Fixture) -> Score {
+    Score { home: fixture.scores.localteam_score, away: fixture.scores.visitorteam_score }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gateway::Gateway;
+    use models::{CardEvent, Wrapper};
+    use mockito::mock;
+    use reqwest::StatusCode;
+    use std::fs;
+    use std::path::Path;
+    use std::sync::Mutex;
+    use transport::{HttpRequest, HttpResponse, Transport};
+
+    fn snapshot() -> Vec<Fixture> {
+        let body = fs::read_to_string(Path::new("src/support/livescores/now_with.json")).expect("Fixtures:");
+        serde_json::from_str::<Wrapper<Vec<Fixture>>>(&body).unwrap().data
+    }
+
+    #[test]
+    fn it_reports_goals_red_cards_and_the_final_whistle() {
+        let before = snapshot();
+        let mut after = before.clone();
+        let id = after[0].id;
+        after[0].scores.localteam_score = before[0].scores.localteam_score + 2;
+        after[0].time.status = FixtureStatus::FullTime;
+        after[0].cards = Some(vec![CardEvent { id: 1, team_id: after[0].visitorteam_id, kind: "redcard".into(), player_name: "R. Ed".into(), ..CardEvent::default() }]);
+
+        let events = diff(&before, &after);
+        let home = before[0].scores.localteam_score;
+        let away = before[0].scores.visitorteam_score;
+
+        assert_eq!(events, vec![
+            LiveEvent::StatusChange { fixture_id: id, from: before[0].time.status.clone(), to: FixtureStatus::FullTime },
+            LiveEvent::Goal { fixture_id: id, side: Side::Home, score: Score { home: home + 1, away } },
+            LiveEvent::Goal { fixture_id: id, side: Side::Home, score: Score { home: home + 2, away } },
+            LiveEvent::RedCard { fixture_id: id, side: Some(Side::Away), player_name: "R. Ed".into() },
+            LiveEvent::FullTime { fixture_id: id, score: Score { home: home + 2, away } },
+        ]);
+    }
+
+    #[test]
+    fn it_reports_kickoffs_and_score_corrections() {
+        let mut before = snapshot();
+        before[0].time.status = FixtureStatus::NotStarted;
+        before[1].scores.visitorteam_score += 1;
+        let late = before.pop().unwrap();
+        let after = snapshot();
+
+        let events = diff(&before, &after);
+
+        assert!(late.is_live());
+        assert!(events.contains(&LiveEvent::Kickoff { fixture_id: late.id }));
+        assert!(events.contains(&LiveEvent::Kickoff { fixture_id: after[0].id }));
+        assert!(events.contains(&LiveEvent::ScoreCorrection { fixture_id: after[1].id, previous: score_of(&before[1]), score: score_of(&after[1]) }));
+        assert!(diff(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn it_polls_the_livescores_and_diffs_snapshots() {
+        let body = fs::read_to_string(Path::new("src/support/livescores/now_with.json")).expect("Fixtures:");
+        let first = mock("GET", "/livescores/now?api_token=1234&include=cards")
+          .with_status(200)
+          .with_body(body.replacen("\"localteam_score\": 0", "\"localteam_score\": 1", 1))
+          .create();
+
+        let mut watcher = LiveWatcher::new(LivescoreGateway::new(Gateway::new("1234".into())), Options::builder().with(FixtureInclude::Cards));
+        assert!(watcher.poll().unwrap().is_empty());
+        first.assert();
+
+        let second = mock("GET", "/livescores/now?api_token=1234&include=cards")
+          .with_status(200)
+          .with_body(body)
+          .create();
+        let events = watcher.poll().unwrap();
+        second.assert();
+
+        assert_eq!(events.len(), 1);
+        match events[0] {
+            LiveEvent::ScoreCorrection { previous, score, .. } => assert_eq!(previous.home, score.home + 1),
+            ref other => panic!("unexpected event {:?}", other)
+        }
+    }
+
+    #[test]
+    fn it_delivers_events_over_a_channel() {
+        let body = fs::read_to_string(Path::new("src/support/livescores/now_with.json")).expect("Fixtures:");
+        let _m = mock("GET", "/livescores/now?api_token=1234&include=events")
+          .with_status(500)
+          .with_body(body)
+          .create();
+
+        let watcher = LiveWatcher::new(LivescoreGateway::new(Gateway::new("1234".into())), Options::builder().with(FixtureInclude::Events))
+            .interval(Duration::from_millis(10));
+        let (events, handle) = watcher.spawn();
+
+        assert_eq!(events.recv().unwrap().unwrap_err().code(), 500);
+        drop(events);
+        handle.join().unwrap();
+    }
+
+    /// Answers every poll with the same snapshot and counts the polls.
+    #[derive(Default)]
+    struct Counting {
+        polls: Mutex<u32>,
+    }
+
+    impl Transport for Counting {
+        fn get(&self, _request: &HttpRequest) -> Result<HttpResponse, SportMonksError> {
+            *self.polls.lock().unwrap() += 1;
+            let body = fs::read_to_string(Path::new("src/support/livescores/now_with.json")).expect("Fixtures:");
+            Ok(HttpResponse { status: StatusCode::OK, retry_after: None, body })
+        }
+    }
+
+    #[test]
+    fn it_stops_polling_once_the_feed_is_dropped() {
+        let transport = Arc::new(Counting::default());
+        let gateway = Gateway::with_transport(transport.clone(), "1234".into(), "https://example.test/v2".into());
+        let watcher = LiveWatcher::new(LivescoreGateway::new(gateway), Options::builder().with(FixtureInclude::Lineup))
+            .interval(Duration::from_millis(50));
+        let (events, handle) = watcher.spawn();
+
+        while *transport.polls.lock().unwrap() == 0 {
+            thread::yield_now();
+        }
+        drop(events);
+        handle.join().unwrap();
+
+        assert_eq!(*transport.polls.lock().unwrap(), 1);
+    }
+}
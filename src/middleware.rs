@@ -0,0 +1,194 @@
+use super::errors::SportMonksError;
+use super::transport::HttpResponse;
+use reqwest::StatusCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+const REDACTED: &str = "REDACTED";
+
+/// A call about to be made by `Gateway::get`, as seen by middleware.
+///
+/// The API token is added after the middleware ran, so it is never part of `query`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestInfo {
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    /// Free-form labels set by middleware, handed back to the response and error hooks.
+    pub tags: Vec<(String, String)>,
+}
+
+impl RequestInfo {
+    /// Adds or replaces the query parameter `name`.
+    pub fn set_param<S: Into<String>>(&mut self, name: &str, value: S) {
+        self.query.retain(|(key, _)| key != name);
+        self.query.push((name.to_string(), value.into()));
+    }
+
+    pub fn tag<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) {
+        self.tags.push((key.into(), value.into()));
+    }
+}
+
+/// A call that returned a decoded response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseInfo {
+    pub status: StatusCode,
+    pub elapsed: Duration,
+    /// Whether a middleware answered instead of the API.
+    pub synthetic: bool,
+}
+
+/// A call that failed, with the API token redacted from the message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailureInfo {
+    pub status: Option<StatusCode>,
+    /// As returned by `SportMonksError::code`.
+    pub code: u32,
+    pub message: String,
+    pub elapsed: Duration,
+}
+
+/// Hooks run around every `Gateway::get`.
+///
+/// `before_request` hooks run in the order the middleware were added and may rewrite the query
+/// or answer with a synthetic response, in which case the later `before_request` hooks, the
+/// cache and the API are skipped. `after_response` and `on_error` hooks then run in reverse
+/// order for every middleware.
+pub trait Middleware: Send + Sync {
+    fn before_request(&self, _request: &mut RequestInfo) -> Option<HttpResponse> {
+        None
+    }
+
+    fn after_response(&self, _request: &RequestInfo, _response: &ResponseInfo) {}
+
+    fn on_error(&self, _request: &RequestInfo, _failure: &FailureInfo) {}
+}
+
+/// The middleware of a gateway, in the order they were added.
+#[derive(Clone, Default)]
+pub(crate) struct Chain {
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl Chain {
+    pub fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middleware.push(middleware);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.middleware.is_empty()
+    }
+
+    pub fn before_request(&self, request: &mut RequestInfo) -> Option<HttpResponse> {
+        self.middleware.iter().filter_map(|middleware| middleware.before_request(request)).next()
+    }
+
+    pub fn after_response(&self, request: &RequestInfo, response: &ResponseInfo) {
+        for middleware in self.middleware.iter().rev() {
+            middleware.after_response(request, response);
+        }
+    }
+
+    pub fn on_error(&self, request: &RequestInfo, api_key: &str, error: &SportMonksError, elapsed: Duration) {
+        let message = error.to_string();
+        let failure = FailureInfo {
+            status: error.status(),
+            code: error.code(),
+            message: if api_key.is_empty() { message } else { message.replace(api_key, REDACTED) },
+            elapsed,
+        };
+        for middleware in self.middleware.iter().rev() {
+            middleware.on_error(request, &failure);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use builder::ClientBuilder;
+    use gateway::Options;
+    use test_util::FakeServer;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder {
+        responses: Mutex<Vec<(RequestInfo, ResponseInfo)>>,
+        failures: Mutex<Vec<(RequestInfo, FailureInfo)>>,
+    }
+
+    impl Middleware for Arc<Recorder> {
+        fn before_request(&self, request: &mut RequestInfo) -> Option<HttpResponse> {
+            request.tag("caller", "tests");
+            if request.path == "/livescores/now" {
+                request.set_param("leagues", "8");
+            }
+            None
+        }
+
+        fn after_response(&self, request: &RequestInfo, response: &ResponseInfo) {
+            self.responses.lock().unwrap().push((request.clone(), response.clone()));
+        }
+
+        fn on_error(&self, request: &RequestInfo, failure: &FailureInfo) {
+            self.failures.lock().unwrap().push((request.clone(), failure.clone()));
+        }
+    }
+
+    struct Maintenance;
+
+    impl Middleware for Maintenance {
+        fn before_request(&self, request: &mut RequestInfo) -> Option<HttpResponse> {
+            if request.path.starts_with("/leagues") {
+                Some(HttpResponse { status: StatusCode::SERVICE_UNAVAILABLE, retry_after: None, body: "down for maintenance".into() })
+            } else {
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn it_runs_the_hooks_around_every_call() {
+        let server = FakeServer::start();
+        let recorder = Arc::new(Recorder::default());
+        let client = ClientBuilder::new("secret-token")
+            .base_url(server.url())
+            .middleware(recorder.clone())
+            .middleware(Maintenance)
+            .build()
+            .unwrap();
+
+        assert!(client.livescores.now().is_ok());
+        assert_eq!(client.leagues.find(2).unwrap_err().code(), 503);
+        assert_eq!(server.requests(), vec!["/livescores/now?api_token=secret-token&leagues=8".to_string()]);
+
+        let responses = recorder.responses.lock().unwrap();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].0.query, vec![("leagues".to_string(), "8".to_string())]);
+        assert_eq!(responses[0].0.tags, vec![("caller".to_string(), "tests".to_string())]);
+        assert_eq!((responses[0].1.status, responses[0].1.synthetic), (StatusCode::OK, false));
+
+        let failures = recorder.failures.lock().unwrap();
+        assert_eq!(failures[0].0.path, "/leagues/2");
+        assert_eq!(failures[0].1.status, Some(StatusCode::SERVICE_UNAVAILABLE));
+    }
+
+    #[test]
+    fn it_redacts_the_token_from_failures() {
+        let unreachable = FakeServer::start().url();
+        let recorder = Arc::new(Recorder::default());
+        let client = ClientBuilder::new("secret-token")
+            .base_url(unreachable)
+            .middleware(recorder.clone())
+            .build()
+            .unwrap();
+
+        let error = client.contients.all_with(Options::builder().per_page(10)).unwrap_err();
+
+        let failures = recorder.failures.lock().unwrap();
+        assert!(!error.to_string().contains("secret-token"));
+        assert!(!format!("{:?}", error).contains("secret-token"));
+        assert!(!failures[0].1.message.contains("secret-token"));
+        assert!(failures[0].1.message.contains(REDACTED));
+    }
+}
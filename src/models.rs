@@ -1,10 +1,24 @@
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{Error, Unexpected};
+use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
+use super::coercion::{self, Coercion, CoercionWarning};
+use super::includes::IncludeReport;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Wrapper<T> {
     pub data: T,
-    pub meta: Option<Meta>
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+    /// Values replaced by defaults while decoding in `Coercion::Lenient` mode.
+    #[serde(skip)]
+    pub warnings: Vec<CoercionWarning>,
+    /// Which of the requested includes the response actually carried.
+    #[serde(skip)]
+    pub includes: IncludeReport,
 }
 
 impl<T> Wrapper<T> {
@@ -16,14 +30,109 @@ impl<T> Wrapper<T> {
         let wrapper = <Self as Deserialize>::deserialize(deserializer)?;
         Ok(wrapper.data)
     }
+
+    /// Writes a relation back in the `{"data": ...}` shape it was read from.
+    fn serialize<S>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct Data<'a, T: 'a> {
+            data: &'a T,
+        }
+        Data { data }.serialize(serializer)
+    }
+
+    pub fn plan(&self) -> Option<&Plan> {
+        self.meta.as_ref().and_then(|meta| meta.plan.as_ref())
+    }
+
+    pub fn subscription(&self) -> Option<&Subscription> {
+        self.meta.as_ref().and_then(|meta| meta.subscription.as_ref())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Meta {
     pub pagination: Option<Pagination>,
+    pub subscription: Option<Subscription>,
+    pub plan: Option<Plan>,
+    #[serde(default)]
+    pub sports: Vec<Sport>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Subscription {
+    #[serde(deserialize_with = "to_datetime", serialize_with = "from_datetime", default)]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "to_datetime", serialize_with = "from_datetime", default)]
+    pub trial_ends_at: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "to_datetime", serialize_with = "from_datetime", default)]
+    pub ends_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl Subscription {
+    pub fn is_trial(&self, now: DateTime<Utc>) -> bool {
+        self.trial_ends_at.is_some_and(|trial_ends_at| now < trial_ends_at)
+    }
+
+    /// When access runs out: the end of the trial while it lasts, then the end of the subscription, if any.
+    pub fn expires_at(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.is_trial(now) {
+            self.trial_ends_at
+        } else {
+            self.ends_at
+        }
+    }
+
+    pub fn expires_within(&self, now: DateTime<Utc>, window: Duration) -> bool {
+        self.expires_at(now).is_some_and(|expires_at| expires_at - now <= window)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Plan {
+    pub name: String,
+    #[serde(deserialize_with = "to_f64", serialize_with = "from_f64")]
+    pub price: f64,
+    #[serde(deserialize_with = "to_request_limit", serialize_with = "from_request_limit")]
+    pub request_limit: RequestLimit,
+}
+
+/// The API budget of a plan: `requests` every `minutes`, sent as `"2000,60"`.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct RequestLimit {
+    pub requests: u32,
+    pub minutes: u32,
+}
+
+impl fmt::Display for RequestLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.requests, self.minutes)
+    }
+}
+
+impl FromStr for RequestLimit {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<RequestLimit, String> {
+        let mut parts = value.split(',').map(|part| part.trim().parse::<u32>());
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(requests)), Some(Ok(minutes)), None) => Ok(RequestLimit { requests, minutes }),
+            _ => Err(format!("invalid request limit {:?}", value)),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sport {
+    pub id: i64,
+    pub name: String,
+    pub current: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Pagination {
     pub total: i64,
     pub count: i64,
@@ -33,15 +142,15 @@ pub struct Pagination {
 }
 
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Continent {
     pub id: i64,
     pub name: String,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub countries: Option<Vec<Country>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Country {
     #[serde(default)]
     pub id: i64,
@@ -49,11 +158,11 @@ pub struct Country {
     pub name: String,
     #[serde(default)]
     pub extra: Option<CountryExtra>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub leagues: Option<Vec<League>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CountryExtra {
     pub continent: Option<String>,
     pub sub_region: Option<String>,
@@ -65,7 +174,7 @@ pub struct CountryExtra {
     pub flag: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct League {
     pub id: i64,
     pub legacy_id: i64,
@@ -78,22 +187,22 @@ pub struct League {
     pub current_stage_id: Option<i64>,
     pub live_standings: bool,
     pub coverage: Coverage,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub seasons: Option<Vec<Season>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub season: Option<Season>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub country: Option<Country>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Coverage {
     pub topscorer_goals: bool,
     pub topscorer_assists: bool,
     pub topscorer_cards: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Season {
     pub id: i64,
     pub name: String,
@@ -101,31 +210,31 @@ pub struct Season {
     pub is_current_season: bool,
     pub current_round_id: Option<i64>,
     pub current_stage_id: Option<i64>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub stages: Option<Vec<Stage>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub rounds: Option<Vec<Round>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub upcoming: Option<Vec<Fixture>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<Fixture>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub groups: Option<Vec<Group>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub goalscorers: Option<Vec<Goals>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub assistscorers: Option<Vec<Assists>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub cardscorers: Option<Vec<Cards>>,
-    #[serde(with = "Wrapper", default, rename = "aggregatedGoalscorers")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "aggregatedGoalscorers")]
     pub aggregated_goalscorers: Option<Vec<Goals>>,
-    #[serde(with = "Wrapper", default, rename = "aggregatedAssistscorers")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "aggregatedAssistscorers")]
     pub aggregated_assistscorers: Option<Vec<Assists>>,
-    #[serde(with = "Wrapper", default, rename = "aggregatedCardscorers")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "aggregatedCardscorers")]
     pub aggregated_cardscorers: Option<Vec<Cards>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Fixture {
     pub id: i64,
     pub league_id: i64,
@@ -152,65 +261,103 @@ pub struct Fixture {
     pub leg: Option<String>,
     pub colors: Option<Colors>,
     pub deleted: bool,
-    #[serde(with = "Wrapper", default, rename = "visitorTeam")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "visitorTeam")]
     pub visitor_team: Option<Team>,
-    #[serde(with = "Wrapper", default, rename = "localTeam")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "localTeam")]
     pub local_team: Option<Team>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub substitutions: Option<Vec<Substitution>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub goals: Option<Vec<GoalEvent>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub cards: Option<Vec<CardEvent>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub events: Option<Vec<Event>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub stage: Option<Stage>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub referee: Option<Referee>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub venue: Option<Venue>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub odds: Option<Vec<Odds>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub inplay: Option<Vec<Odds>>,
-    #[serde(with = "Wrapper", default, rename = "flatOdds")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "flatOdds")]
     pub flat_odds: Option<Vec<FlatOdds>>,
-    #[serde(with = "Wrapper", default, rename = "localCoach")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "localCoach")]
     pub local_coach: Option<Coach>,
-    #[serde(with = "Wrapper", default, rename = "visitorCoach")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "visitorCoach")]
     pub visitor_coach: Option<Coach>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub trends: Option<Vec<Trend>>,
-    #[serde(with = "Wrapper", default, rename = "firstAssistant")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "firstAssistant")]
     pub first_assistant: Option<Assistant>,
-    #[serde(with = "Wrapper", default, rename = "secondAssistant")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "secondAssistant")]
     pub second_assistant: Option<Assistant>,
-    #[serde(with = "Wrapper", default, rename = "fourthOfficial")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "fourthOfficial")]
     pub fourth_official: Option<Assistant>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub corners: Option<Vec<Corner>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub lineup: Option<Vec<PlayerSlot>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub bench: Option<Vec<PlayerSlot>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub sidelined: Option<Vec<Sidelined>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub comments: Option<Vec<Comment>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub tvstations: Option<Vec<TvStation>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub highlights: Option<Vec<Highlight>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub round: Option<Vec<Round>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub group: Option<Vec<Group>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub stats: Option<Vec<Stat>>,
 }
 
+/// The side of a fixture a team plays on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum Side {
+    Home,
+    Away,
+}
+
 impl Fixture {
+    /// Whether `team_id` is the local (home) or visiting (away) team, `None` if it plays neither.
+    pub fn side_of(&self, team_id: i64) -> Option<Side> {
+        if team_id == self.localteam_id {
+            Some(Side::Home)
+        } else if team_id == self.visitorteam_id {
+            Some(Side::Away)
+        } else {
+            None
+        }
+    }
+
+    pub fn kickoff(&self) -> DateTime<Utc> {
+        self.time.starting_at.date_time
+    }
+
+    pub fn status(&self) -> &FixtureStatus {
+        &self.time.status
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.time.status.is_live()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.time.status.is_finished()
+    }
+
+    pub fn is_cancelled_or_postponed(&self) -> bool {
+        self.time.status.is_cancelled_or_postponed()
+    }
+
     pub fn locals_won(&self) -> bool {
         self.scores.localteam_score > self.scores.visitorteam_score
     }
@@ -250,7 +397,7 @@ impl Fixture {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WeatherReport {
     pub code: String,
     #[serde(rename = "type")]
@@ -263,25 +410,25 @@ pub struct WeatherReport {
     pub wind: Wind,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Temperature {
     pub temp: f64,
     pub unit: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Wind {
     pub speed: String,
     pub degree: Option<f64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Formations {
     pub localteam_formation: Option<String>,
     pub visitorteam_formation: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Scores {
     pub localteam_score: i64,
     pub visitorteam_score: i64,
@@ -292,9 +439,132 @@ pub struct Scores {
     pub et_score: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+/// The state of a fixture, as found in `time.status` and accepted by the `status` filter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum FixtureStatus {
+    #[default]
+    NotStarted,
+    Live,
+    HalfTime,
+    FullTime,
+    ExtraTime,
+    PenaltiesLive,
+    AfterExtraTime,
+    Break,
+    FullTimeAfterPenalties,
+    Cancelled,
+    Postponed,
+    Interrupted,
+    Abandoned,
+    Suspended,
+    Awarded,
+    Delayed,
+    ToBeAnnounced,
+    WalkOver,
+    AwaitingUpdates,
+    Deleted,
+    /// A status this crate does not know about yet, kept verbatim.
+    Unknown(String),
+}
+
+impl FixtureStatus {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FixtureStatus::NotStarted => "NS",
+            FixtureStatus::Live => "LIVE",
+            FixtureStatus::HalfTime => "HT",
+            FixtureStatus::FullTime => "FT",
+            FixtureStatus::ExtraTime => "ET",
+            FixtureStatus::PenaltiesLive => "PEN_LIVE",
+            FixtureStatus::AfterExtraTime => "AET",
+            FixtureStatus::Break => "BREAK",
+            FixtureStatus::FullTimeAfterPenalties => "FT_PEN",
+            FixtureStatus::Cancelled => "CANCL",
+            FixtureStatus::Postponed => "POSTP",
+            FixtureStatus::Interrupted => "INT",
+            FixtureStatus::Abandoned => "ABAN",
+            FixtureStatus::Suspended => "SUSP",
+            FixtureStatus::Awarded => "AWARDED",
+            FixtureStatus::Delayed => "DELAYED",
+            FixtureStatus::ToBeAnnounced => "TBA",
+            FixtureStatus::WalkOver => "WO",
+            FixtureStatus::AwaitingUpdates => "AU",
+            FixtureStatus::Deleted => "Deleted",
+            FixtureStatus::Unknown(ref status) => status,
+        }
+    }
+
+    /// In play, including the half time and extra time breaks.
+    pub fn is_live(&self) -> bool {
+        matches!(*self,
+            FixtureStatus::Live | FixtureStatus::HalfTime | FixtureStatus::ExtraTime |
+            FixtureStatus::PenaltiesLive | FixtureStatus::Break)
+    }
+
+    /// Over with a final result, whether played out or decided at the table.
+    pub fn is_finished(&self) -> bool {
+        matches!(*self,
+            FixtureStatus::FullTime | FixtureStatus::AfterExtraTime | FixtureStatus::FullTimeAfterPenalties |
+            FixtureStatus::Awarded | FixtureStatus::WalkOver)
+    }
+
+    pub fn is_cancelled_or_postponed(&self) -> bool {
+        matches!(*self, FixtureStatus::Cancelled | FixtureStatus::Postponed)
+    }
+}
+
+impl FromStr for FixtureStatus {
+    type Err = ();
+
+    fn from_str(status: &str) -> Result<FixtureStatus, ()> {
+        Ok(match status {
+            "NS" => FixtureStatus::NotStarted,
+            "LIVE" => FixtureStatus::Live,
+            "HT" => FixtureStatus::HalfTime,
+            "FT" => FixtureStatus::FullTime,
+            "ET" => FixtureStatus::ExtraTime,
+            "PEN_LIVE" => FixtureStatus::PenaltiesLive,
+            "AET" => FixtureStatus::AfterExtraTime,
+            "BREAK" => FixtureStatus::Break,
+            "FT_PEN" => FixtureStatus::FullTimeAfterPenalties,
+            "CANCL" => FixtureStatus::Cancelled,
+            "POSTP" => FixtureStatus::Postponed,
+            "INT" => FixtureStatus::Interrupted,
+            "ABAN" => FixtureStatus::Abandoned,
+            "SUSP" => FixtureStatus::Suspended,
+            "AWARDED" => FixtureStatus::Awarded,
+            "DELAYED" => FixtureStatus::Delayed,
+            "TBA" => FixtureStatus::ToBeAnnounced,
+            "WO" => FixtureStatus::WalkOver,
+            "AU" => FixtureStatus::AwaitingUpdates,
+            "Deleted" => FixtureStatus::Deleted,
+            other => FixtureStatus::Unknown(other.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for FixtureStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for FixtureStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FixtureStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<FixtureStatus, D::Error> {
+        let status = String::deserialize(deserializer)?;
+        Ok(status.parse().unwrap_or(FixtureStatus::Unknown(status)))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Time {
-    pub status: String,
+    pub status: FixtureStatus,
     pub starting_at: StartingAt,
     pub minute: Option<i64>,
     pub second: Option<i64>,
@@ -303,47 +573,97 @@ pub struct Time {
     pub injury_time: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawStartingAt", into = "RawStartingAt")]
 pub struct StartingAt {
-    pub date_time: String,
-    pub date: String,
-    pub time: String,
+    /// The kick-off instant, read from `timestamp`.
+    pub date_time: DateTime<Utc>,
+    /// The kick-off date in `timezone`.
+    pub date: NaiveDate,
+    /// The kick-off time in `timezone`.
+    pub time: NaiveTime,
     pub timestamp: i64,
     pub timezone: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Serialize, Deserialize)]
+struct RawStartingAt {
+    date_time: String,
+    date: String,
+    time: String,
+    timestamp: i64,
+    timezone: String,
+}
+
+impl From<StartingAt> for RawStartingAt {
+    fn from(starting_at: StartingAt) -> RawStartingAt {
+        RawStartingAt {
+            date_time: format!("{} {}", starting_at.date.format("%Y-%m-%d"), starting_at.time.format("%H:%M:%S")),
+            date: starting_at.date.format("%Y-%m-%d").to_string(),
+            time: starting_at.time.format("%H:%M:%S").to_string(),
+            timestamp: starting_at.timestamp,
+            timezone: starting_at.timezone,
+        }
+    }
+}
+
+impl TryFrom<RawStartingAt> for StartingAt {
+    type Error = String;
+
+    /// Trusts `timestamp`, the one unambiguous field. A local `date_time` that does not resolve to
+    /// the same instant, e.g. a time skipped by a DST change, is recorded as a coercion warning.
+    fn try_from(raw: RawStartingAt) -> Result<StartingAt, String> {
+        let date_time = Utc.timestamp_opt(raw.timestamp, 0).single()
+            .ok_or_else(|| format!("invalid timestamp {}", raw.timestamp))?;
+        if resolve_datetime(&raw.date_time, 3, &raw.timezone).ok() != Some(date_time) {
+            coercion::warn(&raw.date_time, "the local time of the timestamp");
+        }
+        let local = match raw.timezone.parse::<Tz>() {
+            Ok(tz) => date_time.with_timezone(&tz).naive_local(),
+            Err(_) => date_time.naive_utc(),
+        };
+        Ok(StartingAt {
+            date_time,
+            date: NaiveDate::parse_from_str(&raw.date, "%Y-%m-%d").unwrap_or_else(|_| local.date()),
+            time: NaiveTime::parse_from_str(&raw.time, "%H:%M:%S").unwrap_or_else(|_| local.time()),
+            timestamp: raw.timestamp,
+            timezone: raw.timezone,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Coaches {
     pub localteam_coach_id: Option<i64>,
     pub visitorteam_coach_id: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Standings {
     pub localteam_position: Option<i64>,
     pub visitorteam_position: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Assistants {
     pub first_assistant_id: Option<i64>,
     pub second_assistant_id: Option<i64>,
     pub fourth_official_id: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Colors {
     pub localteam: ColorKit,
     pub visitorteam: ColorKit,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ColorKit {
     pub color: Option<String>,
     pub kit_colors: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Goals {
     pub position: i64,
     pub season_id: i64,
@@ -358,7 +678,7 @@ pub struct Goals {
     pub kind: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Assists {
     pub position: i64,
     pub season_id: i64,
@@ -371,7 +691,7 @@ pub struct Assists {
     pub kind: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Cards {
     pub position: i64,
     pub season_id: i64,
@@ -384,18 +704,20 @@ pub struct Cards {
     pub kind: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Round {
     pub id: i64,
     pub name: i64,
     pub league_id: i64,
     pub season_id: i64,
     pub stage_id: i64,
-    pub start: String,
-    pub end: String,
+    #[serde(deserialize_with = "to_date", default)]
+    pub start: Option<NaiveDate>,
+    #[serde(deserialize_with = "to_date", default)]
+    pub end: Option<NaiveDate>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Stage {
     pub id: i64,
     pub name: String,
@@ -405,7 +727,7 @@ pub struct Stage {
     pub season_id: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Group {
     pub id: i64,
     pub name: String,
@@ -417,7 +739,7 @@ pub struct Group {
     pub stage_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Team {
     pub id: i64,
     pub legacy_id: Option<i64>,
@@ -430,51 +752,52 @@ pub struct Team {
     pub logo_path: Option<String>,
     pub venue_id: Option<i64>,
     pub current_season_id: Option<i64>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub country: Option<Country>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub squad: Option<Vec<PlayerInMatch>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub coach: Option<Coach>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub transfers: Option<Vec<Transfer>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub sidelined: Option<Vec<SidelinedOnTeam>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub stats: Option<Vec<TeamStats>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub venue: Option<Venue>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub uefaranking: Option<UefaRanking>,
-    #[serde(with = "Wrapper", default, rename = "visitorFixtures")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "visitorFixtures")]
     pub visitor_fixtures: Option<Vec<Fixture>>,
-    #[serde(with = "Wrapper", default, rename = "localFixtures")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "localFixtures")]
     pub local_fixtures: Option<Vec<Fixture>>,
-    #[serde(with = "Wrapper", default, rename = "visitorResults")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "visitorResults")]
     pub visitor_results: Option<Vec<Fixture>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub latest: Option<Vec<Fixture>>, 
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub upcoming: Option<Vec<Fixture>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub goalscorers: Option<Vec<Goals>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub cardscorers: Option<Vec<Cards>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub assistscorers: Option<Vec<Assists>>,
-    #[serde(with = "Wrapper", default, rename = "aggregatedGoalscorers")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "aggregatedGoalscorers")]
     pub aggregated_goalscorers: Option<Vec<Goals>>,
-    #[serde(with = "Wrapper", default, rename = "aggregatedCardscorers")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "aggregatedCardscorers")]
     pub aggregated_cardscorers: Option<Vec<Cards>>, 
-    #[serde(with = "Wrapper", default, rename = "aggregatedAssistscorers")]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none", rename = "aggregatedAssistscorers")]
     pub aggregated_assistscorers: Option<Vec<Assists>>,
 
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Substitution {
     pub id: i64,
-    pub team_id: String,
+    #[serde(deserialize_with = "to_i64", serialize_with = "from_i64")]
+    pub team_id: i64,
     #[serde(rename = "type")]
     pub kind: String,
     pub fixture_id: i64,
@@ -487,10 +810,11 @@ pub struct Substitution {
     pub injuried: Option<bool>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GoalEvent {
     pub id: i64,
-    pub team_id: String,
+    #[serde(deserialize_with = "to_i64", serialize_with = "from_i64")]
+    pub team_id: i64,
     #[serde(rename = "type")]
     pub kind: String,
     pub fixture_id: i64,
@@ -504,10 +828,11 @@ pub struct GoalEvent {
     pub result: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CardEvent {
     pub id: i64,
-    pub team_id: String,
+    #[serde(deserialize_with = "to_i64", serialize_with = "from_i64")]
+    pub team_id: i64,
     #[serde(rename = "type")]
     pub kind: String,
     pub fixture_id: i64,
@@ -518,10 +843,11 @@ pub struct CardEvent {
     pub reason: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Event {
     pub id: i64,
-    pub team_id: String,
+    #[serde(deserialize_with = "to_i64", serialize_with = "from_i64")]
+    pub team_id: i64,
     #[serde(rename = "type")]
     pub kind: String,
     pub fixture_id: i64,
@@ -536,21 +862,15 @@ pub struct Event {
     pub result: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Highlight {
     pub fixture_id: i64,
     pub location: String,
-    pub created_at: CreatedAt,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-pub struct CreatedAt {
-    pub date: String,
-    pub timezone_type: i64,
-    pub timezone: String,
+    #[serde(deserialize_with = "to_utc", serialize_with = "from_utc")]
+    pub created_at: DateTime<Utc>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Referee {
     pub id: i64,
     pub common_name: String,
@@ -559,7 +879,7 @@ pub struct Referee {
     pub lastname: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Venue {
     pub id: i64,
     pub name: String,
@@ -571,7 +891,7 @@ pub struct Venue {
     pub coordinates: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Odds {
     pub id: i64,
     pub name: String,
@@ -579,7 +899,7 @@ pub struct Odds {
     pub bookmaker: Vec<Bookmaker>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Bookmaker {
     pub id: i64,
     pub name: String,
@@ -588,7 +908,7 @@ pub struct Bookmaker {
 }
 
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BookmakerOdds {
     pub label: String,
     #[serde(deserialize_with = "to_string")]
@@ -597,10 +917,11 @@ pub struct BookmakerOdds {
     pub handicap: Option<String>,
     pub total: Option<String>,
     pub bookmaker_event_id: Option<String>,
-    pub last_update: UpdatedAt,
+    #[serde(deserialize_with = "to_utc", serialize_with = "from_utc")]
+    pub last_update: DateTime<Utc>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FlatOdds {
     pub bookmaker_id: i64,
     pub bookmaker_event_id: Option<String>,
@@ -608,7 +929,7 @@ pub struct FlatOdds {
     pub odds: Vec<BookmakerOdds>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Coach {
     pub coach_id: i64,
     pub team_id: i64,
@@ -618,13 +939,14 @@ pub struct Coach {
     pub firstname: String,
     pub lastname: String,
     pub nationality: String,
-    pub birthdate: String,
+    #[serde(deserialize_with = "to_birthdate", serialize_with = "from_birthdate", default)]
+    pub birthdate: Option<NaiveDate>,
     pub birthcountry: String,
     pub birthplace: String,
     pub image_path: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Trend {
     pub id: i64,
     pub fixture_id: i64,
@@ -632,23 +954,17 @@ pub struct Trend {
     #[serde(rename = "type")]
     pub kind: String,
     pub analyses: Vec<Analysis>,
-    pub updated_at: UpdatedAt,
+    #[serde(deserialize_with = "to_utc", serialize_with = "from_utc")]
+    pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Analysis {
     pub minute: String,
     pub amount: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-pub struct UpdatedAt {
-    pub date: String,
-    pub timezone_type: i64,
-    pub timezone: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Assistant {
     pub id: i64,
     pub common_name: String,
@@ -657,7 +973,7 @@ pub struct Assistant {
     pub lastname: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Stat {
     pub team_id: i64,
     pub fixture_id: i64,
@@ -695,7 +1011,7 @@ pub struct Stat {
     pub ball_safe: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Shots {
     #[serde(deserialize_with = "to_i64")]
     pub total: i64,
@@ -711,7 +1027,7 @@ pub struct Shots {
     pub outsidebox: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Passes {
     #[serde(deserialize_with = "to_i64")]
     pub total: i64,
@@ -721,7 +1037,7 @@ pub struct Passes {
     pub percentage: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Attacks {
     #[serde(deserialize_with = "to_i64")]
     pub attacks: i64,
@@ -729,7 +1045,7 @@ pub struct Attacks {
     pub dangerous_attacks: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Corner {
     pub id: i64,
     pub team_id: i64,
@@ -739,7 +1055,7 @@ pub struct Corner {
     pub comment: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayerSlot {
     pub team_id: i64,
     pub fixture_id: i64,
@@ -754,7 +1070,7 @@ pub struct PlayerSlot {
     pub stats: Stats,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Stats {
     pub shots: ShotsPerPlayer,
     pub goals: GoalsPerPlayer,
@@ -766,7 +1082,7 @@ pub struct Stats {
     pub other: OtherStatsPerPlayer,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ShotsPerPlayer {
     #[serde(deserialize_with = "to_i64")]
     pub shots_total: i64,
@@ -774,7 +1090,7 @@ pub struct ShotsPerPlayer {
     pub shots_on_goal: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GoalsPerPlayer {
     #[serde(deserialize_with = "to_i64")]
     pub scored: i64,
@@ -784,7 +1100,7 @@ pub struct GoalsPerPlayer {
     pub conceded: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FoulsPerPlayer {
     #[serde(deserialize_with = "to_i64")]
     pub drawn: i64,
@@ -792,7 +1108,7 @@ pub struct FoulsPerPlayer {
     pub committed: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CardsPerPlayer {
     #[serde(deserialize_with = "to_i64")]
     pub yellowcards: i64,
@@ -800,7 +1116,7 @@ pub struct CardsPerPlayer {
     pub redcards: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PassingPerPlayer {
     #[serde(deserialize_with = "to_i64")]
     pub total_crosses: i64,
@@ -814,7 +1130,7 @@ pub struct PassingPerPlayer {
     pub key_passes: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DribblesPerPlayer {
     #[serde(deserialize_with = "to_i64")]
     pub attempts: i64,
@@ -824,7 +1140,7 @@ pub struct DribblesPerPlayer {
     pub dribbled_past: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DuelsPerPlayer {
     #[serde(deserialize_with = "to_i64")]
     pub total: i64,
@@ -832,7 +1148,7 @@ pub struct DuelsPerPlayer {
     pub won: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OtherStatsPerPlayer {
     #[serde(deserialize_with = "to_i64")]
     pub offsides: i64,
@@ -866,7 +1182,7 @@ pub struct OtherStatsPerPlayer {
     pub minutes_played: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Sidelined {
     pub team_id: Option<i64>,
     #[serde(default)]
@@ -878,16 +1194,18 @@ pub struct Sidelined {
     pub reason: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SidelinedOnTeam {
     pub player_id: i64,
     pub team_id: i64,
     pub description: String,
-    pub start_date: String,
-    pub end_date: Option<String>,
+    #[serde(deserialize_with = "to_date", default)]
+    pub start_date: Option<NaiveDate>,
+    #[serde(deserialize_with = "to_date", default)]
+    pub end_date: Option<NaiveDate>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Comment {
     pub fixture_id: i64,
     pub important: bool,
@@ -898,13 +1216,13 @@ pub struct Comment {
     pub comment: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TvStation {
     pub fixture_id: i64,
     pub tvstation: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Standing {
     pub name: String,
     pub league_id: i64,
@@ -917,7 +1235,7 @@ pub struct Standing {
     pub standings: Vec<StandingPosition>
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StandingPosition {
     pub position: i64,
     pub team_id: i64,
@@ -934,11 +1252,11 @@ pub struct StandingPosition {
     pub points: i64,
     pub recent_form: String,
     pub status: String,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub team: Option<Team>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OverallStats {
     pub games_played: i64,
     pub won: i64,
@@ -948,7 +1266,7 @@ pub struct OverallStats {
     pub goals_against: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HomeStats {
     pub games_played: i64,
     pub won: i64,
@@ -958,7 +1276,7 @@ pub struct HomeStats {
     pub goals_against: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwayStats {
     pub games_played: i64,
     pub won: i64,
@@ -968,13 +1286,13 @@ pub struct AwayStats {
     pub goals_against: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TotalStats {
     pub goal_difference: i64,
     pub points: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LiveStanding {
     pub position: i64,
     pub played: i64,
@@ -992,7 +1310,7 @@ pub struct LiveStanding {
     pub fairplay_points_lose: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayerInMatch {
     pub player_id: i64,
     #[serde(default)]
@@ -1039,11 +1357,11 @@ pub struct PlayerInMatch {
     pub duels: Duels,
     pub passes: PassesPerPlayer,
     pub penalties: Penalties,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub player: Option<Player>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Duels {
     #[serde(deserialize_with = "to_i64")]
     pub total: i64,
@@ -1051,7 +1369,7 @@ pub struct Duels {
     pub won: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PassesPerPlayer {
     #[serde(deserialize_with = "to_i64")]
     pub total: i64,
@@ -1061,7 +1379,7 @@ pub struct PassesPerPlayer {
     pub key_passes: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Penalties {
     #[serde(deserialize_with = "to_i64")]
     pub won: i64,
@@ -1075,7 +1393,7 @@ pub struct Penalties {
     pub saves: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Crosses {
     #[serde(deserialize_with = "to_i64")]
     pub total: i64,
@@ -1083,7 +1401,7 @@ pub struct Crosses {
     pub accurate: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Transfer {
     pub player_id: i64,
     pub from_team_id: i64,
@@ -1096,7 +1414,7 @@ pub struct Transfer {
     pub amount: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TeamStats {
     pub team_id: i64,
     pub season_id: i64,
@@ -1115,7 +1433,7 @@ pub struct TeamStats {
     pub failed_to_score: HomeAwayTotalStat,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HomeAwayTotalStat {
     #[serde(deserialize_with = "to_f64")]
     pub total: f64,
@@ -1125,12 +1443,12 @@ pub struct HomeAwayTotalStat {
     pub away: f64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScoringMinute {
     pub period: Vec<Period>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Period {
     pub minute: String,
     #[serde(deserialize_with = "to_i64")]
@@ -1138,7 +1456,7 @@ pub struct Period {
     pub percentage: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UefaRanking {
     pub team_id: i64,
     pub points: Option<i64>,
@@ -1148,7 +1466,7 @@ pub struct UefaRanking {
     pub position_won_or_lost: Option<i64>
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Player {
     pub player_id: i64,
     pub team_id: Option<i64>,
@@ -1159,23 +1477,24 @@ pub struct Player {
     pub firstname: Option<String>,
     pub lastname: Option<String>,
     pub nationality: Option<String>,
-    pub birthdate: Option<String>,
+    #[serde(deserialize_with = "to_birthdate", serialize_with = "from_birthdate", default)]
+    pub birthdate: Option<NaiveDate>,
     pub birthcountry: Option<String>,
     pub birthplace: Option<String>,
     pub height: Option<String>,
     pub weight: Option<String>,
     pub image_path: Option<String>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub trophies: Option<Vec<Trophy>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub transfers: Option<Vec<Transfer>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub sidelined: Option<Vec<Sidelined>>,
-    #[serde(with = "Wrapper", default)]
+    #[serde(with = "Wrapper", default, skip_serializing_if = "Option::is_none")]
     pub position: Option<Position>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Trophy {
     pub player_id: i64,
     pub status: String,
@@ -1186,7 +1505,7 @@ pub struct Trophy {
     pub seasons: Vec<Season>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Position {
     pub id: i64,
     pub name: String,
@@ -1214,7 +1533,7 @@ where
 {
     match FloatOrString::deserialize(deserializer)? {
         FloatOrString::F64(v) => Ok(v),
-        FloatOrString::Stringz(v) => { Ok(f64::from_str(&v.replace("m", "")).unwrap()) },
+        FloatOrString::Stringz(v) => coerce(&v, "a float", |v| f64::from_str(v).ok()),
         FloatOrString::I64(v) => Ok(v as f64),
         FloatOrString::Nothing => Ok(0.0 as f64),
     }
@@ -1226,16 +1545,285 @@ where
 {
     match FloatOrString::deserialize(deserializer)? {
         FloatOrString::F64(v) => Ok(v as i64),
-        FloatOrString::Stringz(v) => { Ok(i64::from_str(&v.replace("m", "")).unwrap()) },
+        FloatOrString::Stringz(v) => coerce(&v, "an integer", |v| {
+            i64::from_str(v).ok().or_else(|| f64::from_str(v).ok().map(|v| v as i64))
+        }),
         FloatOrString::I64(v) => Ok(v),
         FloatOrString::Nothing => Ok(0),
     }
 }
 
+/// Parses numbers sent as strings (`"12"`, `"90m"`), falling back according to the coercion mode.
+fn coerce<E: Error, R: Default, F: Fn(&str) -> Option<R>>(value: &str, expected: &'static str, parse: F) -> Result<R, E> {
+    let trimmed = value.trim().trim_end_matches('m');
+    if trimmed.is_empty() {
+        return Ok(R::default());
+    }
+    match parse(trimmed) {
+        Some(parsed) => Ok(parsed),
+        None => match coercion::mode() {
+            Coercion::Lenient => {
+                coercion::warn(value, expected);
+                Ok(R::default())
+            },
+            Coercion::Strict => Err(E::invalid_value(Unexpected::Str(value), &expected)),
+        }
+    }
+}
+
+/// The `{date, timezone_type, timezone}` objects PHP emits for dates, or an RFC 3339 string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawDateTime {
+    Php { date: String, timezone_type: i64, timezone: String },
+    Rfc3339(String),
+}
+
+impl RawDateTime {
+    fn resolve(&self) -> Result<DateTime<Utc>, String> {
+        match *self {
+            RawDateTime::Php { ref date, timezone_type, ref timezone } => resolve_datetime(date, timezone_type, timezone),
+            RawDateTime::Rfc3339(ref date) => DateTime::parse_from_rfc3339(date)
+                .map(|date| date.with_timezone(&Utc))
+                .map_err(|error| format!("invalid date {:?}: {}", date, error)),
+        }
+    }
+}
+
+fn to_datetime<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<RawDateTime>::deserialize(deserializer)? {
+        Some(raw) => raw.resolve().map(Some).map_err(D::Error::custom),
+        None => Ok(None),
+    }
+}
+
+fn to_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    RawDateTime::deserialize(deserializer)?.resolve().map_err(D::Error::custom)
+}
+
+/// Reads `2018-09-18` dates, falling back according to the coercion mode.
+fn to_date<'de, D, R>(deserializer: D) -> Result<R, D::Error>
+where
+    D: Deserializer<'de>,
+    R: Default + From<NaiveDate>,
+{
+    let date = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+    coerce(&date, "a date", |date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok().map(R::from))
+}
+
+/// Reads `26/04/1977` birthdates, falling back according to the coercion mode.
+fn to_birthdate<'de, D, R>(deserializer: D) -> Result<R, D::Error>
+where
+    D: Deserializer<'de>,
+    R: Default + From<NaiveDate>,
+{
+    let date = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+    coerce(&date, "a date", |date| NaiveDate::parse_from_str(date, "%d/%m/%Y").ok().map(R::from))
+}
+
+fn resolve_datetime(date: &str, timezone_type: i64, timezone: &str) -> Result<DateTime<Utc>, String> {
+    let naive = NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S%.f")
+        .map_err(|error| format!("invalid date {:?}: {}", date, error))?;
+    let local = if timezone == "UTC" || timezone == "Z" {
+        Utc.from_local_datetime(&naive).single()
+    } else if timezone_type == 1 {
+        let offset = DateTime::parse_from_str(&format!("2000-01-01T00:00:00{}", timezone), "%Y-%m-%dT%H:%M:%S%:z")
+            .map(|parsed| *parsed.offset())
+            .map_err(|_| format!("invalid offset {:?}", timezone))?;
+        FixedOffset::from_local_datetime(&offset, &naive).single().map(|date| date.with_timezone(&Utc))
+    } else {
+        let tz = timezone.parse::<Tz>().map_err(|_| format!("unknown timezone {:?}", timezone))?;
+        tz.from_local_datetime(&naive).earliest().map(|date| date.with_timezone(&Utc))
+    };
+    local.ok_or_else(|| format!("{:?} does not exist in {}", date, timezone))
+}
+
+/// The object PHP emits for dates, written back in UTC.
+#[derive(Serialize)]
+struct PhpDateTime {
+    date: String,
+    timezone_type: i64,
+    timezone: &'static str,
+}
+
+/// Writes dates back as `{"date": "2019-02-12 00:11:17.000000", "timezone_type": 3, "timezone": "UTC"}`.
+fn from_utc<S: Serializer>(date: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+    PhpDateTime { date: date.format("%Y-%m-%d %H:%M:%S%.6f").to_string(), timezone_type: 3, timezone: "UTC" }.serialize(serializer)
+}
+
+fn from_datetime<S: Serializer>(date: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error> {
+    match *date {
+        Some(ref date) => from_utc(date, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Writes numbers the API sends as strings back as strings, e.g. `"20"`.
+fn from_f64<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+fn from_i64<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+fn to_request_limit<'de, D>(deserializer: D) -> Result<RequestLimit, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+}
+
+fn from_request_limit<S: Serializer>(limit: &RequestLimit, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(limit)
+}
+
+/// Writes birthdates back as `26/04/1977`.
+fn from_birthdate<S: Serializer, R: Into<Option<NaiveDate>> + Copy>(date: &R, serializer: S) -> Result<S::Ok, S::Error> {
+    match (*date).into() {
+        Some(date) => serializer.collect_str(&date.format("%d/%m/%Y")),
+        None => serializer.serialize_none(),
+    }
+}
+
 fn parse_default<'de, D, R>(d: D) -> Result<R, D::Error>
   where D: Deserializer<'de>, R: Default + Deserialize<'de> {
     Deserialize::deserialize(d)
         .map(|x: Option<_>| {
             x.unwrap_or(R::default())
         })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn it_honours_the_timezone_of_subscription_dates() {
+        let body = r#"{
+            "started_at": {"date": "2019-07-01 12:00:00.000000", "timezone_type": 3, "timezone": "Europe/Amsterdam"},
+            "trial_ends_at": {"date": "2019-07-01 12:00:00.000000", "timezone_type": 1, "timezone": "-03:00"},
+            "ends_at": null
+        }"#;
+
+        let subscription: Subscription = serde_json::from_str(body).unwrap();
+        assert_eq!(subscription.started_at, Some(Utc.with_ymd_and_hms(2019, 7, 1, 10, 0, 0).unwrap()));
+        assert_eq!(subscription.trial_ends_at, Some(Utc.with_ymd_and_hms(2019, 7, 1, 15, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn it_keeps_unknown_fixture_statuses() {
+        let statuses: Vec<FixtureStatus> = serde_json::from_str(r#"["FT_PEN", "POSTP", "NEW_STATUS"]"#).unwrap();
+
+        assert_eq!(statuses, vec![
+            FixtureStatus::FullTimeAfterPenalties,
+            FixtureStatus::Postponed,
+            FixtureStatus::Unknown("NEW_STATUS".into())
+        ]);
+        assert!(statuses[0].is_finished());
+        assert!(statuses[1].is_cancelled_or_postponed());
+        assert_eq!(statuses[2].to_string(), "NEW_STATUS");
+    }
+
+    #[test]
+    fn it_resolves_kickoffs_in_the_requested_timezone() {
+        let body = r#"{
+            "date_time": "2019-02-20 21:00:00", "date": "2019-02-20", "time": "21:00:00",
+            "timestamp": 1550692800, "timezone": "Europe/Amsterdam"
+        }"#;
+
+        let starting_at: StartingAt = serde_json::from_str(body).unwrap();
+        assert_eq!(starting_at.date_time, Utc.with_ymd_and_hms(2019, 2, 20, 20, 0, 0).unwrap());
+        assert_eq!(starting_at.date_time.timestamp(), starting_at.timestamp);
+        assert_eq!(starting_at.time, NaiveTime::from_hms_opt(21, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn it_trusts_the_timestamp_of_kickoffs_in_a_dst_gap() {
+        let body = r#"{
+            "date_time": "2019-03-31 02:30:00", "date": "2019-03-31", "time": "02:30:00",
+            "timestamp": 1553995800, "timezone": "Europe/Amsterdam"
+        }"#;
+
+        let (starting_at, warnings) = coercion::with_mode(Coercion::Lenient, || serde_json::from_str::<StartingAt>(body));
+        let starting_at = starting_at.unwrap();
+
+        assert_eq!(starting_at.date_time, Utc.with_ymd_and_hms(2019, 3, 31, 1, 30, 0).unwrap());
+        assert_eq!(starting_at.time, NaiveTime::from_hms_opt(2, 30, 0).unwrap());
+        assert_eq!(warnings, vec![CoercionWarning { value: "2019-03-31 02:30:00".into(), expected: "the local time of the timestamp" }]);
+    }
+
+    fn round_trip<T: serde::de::DeserializeOwned + Serialize + PartialEq + fmt::Debug>(path: &str) {
+        let body = fs::read_to_string(Path::new(path)).expect("Fixtures:");
+        let response: Wrapper<T> = serde_json::from_str(&body).unwrap();
+        let serialized = serde_json::to_string(&response).unwrap();
+
+        assert_eq!(serde_json::from_str::<Wrapper<T>>(&serialized).unwrap(), response, "{}", path);
+    }
+
+    /// Compares the dates, prices and team ids of `serialized` with the payload they were read from.
+    fn assert_wire_fields(original: &serde_json::Value, serialized: &serde_json::Value, at: &str) {
+        use serde_json::Value;
+        match (original, serialized) {
+            (Value::Object(original), Value::Object(serialized)) => for (key, value) in original {
+                let path = format!("{}.{}", at, key);
+                match (key.as_str(), serialized.get(key)) {
+                    ("started_at", Some(field)) | ("trial_ends_at", Some(field)) | ("ends_at", Some(field)) | ("price", Some(field)) |
+                    ("created_at", Some(field)) | ("last_update", Some(field)) | ("updated_at", Some(field)) | ("team_id", Some(field)) =>
+                        assert_eq!(field, value, "{}", path),
+                    (_, Some(serialized)) => assert_wire_fields(value, serialized, &path),
+                    (_, None) => (),
+                }
+            },
+            (Value::Array(original), Value::Array(serialized)) => for (index, (value, serialized)) in original.iter().zip(serialized).enumerate() {
+                assert_wire_fields(value, serialized, &format!("{}[{}]", at, index));
+            },
+            _ => (),
+        }
+    }
+
+    fn wire_shape<T: serde::de::DeserializeOwned + Serialize>(path: &str) {
+        let body = fs::read_to_string(Path::new(path)).expect("Fixtures:");
+        let response: Wrapper<T> = serde_json::from_str(&body).unwrap();
+
+        assert_wire_fields(&serde_json::from_str(&body).unwrap(), &serde_json::to_value(&response).unwrap(), path);
+    }
+
+    #[test]
+    fn it_serializes_models_back_to_the_wire_shape() {
+        round_trip::<Fixture>("src/support/fixtures/find_with_many_stuff.json");
+        round_trip::<Vec<Fixture>>("src/support/fixtures/on_with.json");
+        round_trip::<Vec<Fixture>>("src/support/livescores/now_with.json");
+        round_trip::<Team>("src/support/teams/find_with.json");
+        round_trip::<Season>("src/support/seasons/find_with_rounds.json");
+        round_trip::<Vec<Standing>>("src/support/standings/find.json");
+        round_trip::<Player>("src/support/players/find_with.json");
+        round_trip::<Vec<Continent>>("src/support/continents/all.json");
+        wire_shape::<Vec<Continent>>("src/support/continents/all.json");
+        wire_shape::<Vec<Fixture>>("src/support/fixtures/team_between_with.json");
+        wire_shape::<Fixture>("src/support/fixtures/find_with_many_stuff.json");
+        wire_shape::<Vec<Fixture>>("src/support/fixtures/on_with.json");
+
+        let continents: serde_json::Value = serde_json::from_str(&fs::read_to_string("src/support/continents/all.json").unwrap()).unwrap();
+        let serialized = serde_json::to_value(serde_json::from_value::<Wrapper<Vec<Continent>>>(continents.clone()).unwrap()).unwrap();
+        assert_eq!(serialized["meta"]["subscription"]["started_at"], continents["meta"]["subscription"]["started_at"]);
+        assert_eq!(serialized["meta"]["plan"]["price"], "20");
+
+        let body = fs::read_to_string(Path::new("src/support/fixtures/find_with.json")).expect("Fixtures:");
+        let fixture: Wrapper<Fixture> = serde_json::from_str(&body).unwrap();
+        let value = serde_json::to_value(&fixture.data).unwrap();
+        assert!(value["localTeam"]["data"]["id"].is_i64());
+        assert!(value.get("lineup").is_none());
+        assert_eq!(value["time"]["status"], "NS");
+        assert_eq!(value["time"]["starting_at"]["date_time"], "2019-02-20 20:00:00");
+    }
+}
@@ -1,8 +1,9 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Comment,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
 
 
+#[derive(Clone)]
 pub struct CommentariesGateway {
     gateway: Gateway
 }
@@ -17,6 +18,21 @@ impl CommentariesGateway {
     }
 }
 
+#[derive(Clone)]
+pub struct AsyncCommentariesGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncCommentariesGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncCommentariesGateway {
+        AsyncCommentariesGateway { gateway }
+    }
+
+    pub fn all_for(&self, fixture_id: i64) -> SportMonksFuture<Wrapper<Vec<Comment>>> {
+        self.gateway.get(&format!("/commentaries/fixture/{}", fixture_id), Options::empty())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,8 +1,11 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Continent,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::ContinentInclude;
+use super::super::pagination::{Pages,PageStream};
 
 
+#[derive(Clone)]
 pub struct ContinentGateway {
     gateway: Gateway
 }
@@ -16,8 +19,12 @@ impl ContinentGateway {
         self.gateway.get("/continents", Options::empty())
     }
     
-    pub fn all_with(&self, options: Options) -> Result<Wrapper<Vec<Continent>>, SportMonksError> {
-        self.gateway.get("/continents", options)
+    pub fn all_with(&self, options: Options<ContinentInclude>) -> Result<Wrapper<Vec<Continent>>, SportMonksError> {
+        self.gateway.get("/continents", options.untyped())
+    }
+
+    pub fn all_pages(&self, options: Options<ContinentInclude>) -> Pages<Continent> {
+        Pages::new(self.gateway.clone(), "/continents", options.untyped())
     }
     
     pub fn find(&self, id: i64) -> Result<Wrapper<Continent>, SportMonksError> {
@@ -25,9 +32,42 @@ impl ContinentGateway {
         self.gateway.get(&path, Options::empty())
     }
 
-    pub fn find_with(&self, id: i64, options: Options) -> Result<Wrapper<Continent>, SportMonksError> {
+    pub fn find_with(&self, id: i64, options: Options<ContinentInclude>) -> Result<Wrapper<Continent>, SportMonksError> {
+        let path = format!("/continents/{}", id);
+        self.gateway.get(&path, options.untyped())
+    }
+}
+
+#[derive(Clone)]
+pub struct AsyncContinentGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncContinentGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncContinentGateway {
+        AsyncContinentGateway { gateway }
+    }
+
+    pub fn all(&self) -> SportMonksFuture<Wrapper<Vec<Continent>>> {
+        self.gateway.get("/continents", Options::empty())
+    }
+    
+    pub fn all_with(&self, options: Options<ContinentInclude>) -> SportMonksFuture<Wrapper<Vec<Continent>>> {
+        self.gateway.get("/continents", options.untyped())
+    }
+
+    pub fn all_pages(&self, options: Options<ContinentInclude>) -> PageStream<Continent> {
+        PageStream::new(self.gateway.clone(), "/continents", options.untyped())
+    }
+    
+    pub fn find(&self, id: i64) -> SportMonksFuture<Wrapper<Continent>> {
+        let path = format!("/continents/{}", id);
+        self.gateway.get(&path, Options::empty())
+    }
+
+    pub fn find_with(&self, id: i64, options: Options<ContinentInclude>) -> SportMonksFuture<Wrapper<Continent>> {
         let path = format!("/continents/{}", id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
     }
 }
 
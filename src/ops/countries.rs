@@ -1,8 +1,11 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Country,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::CountryInclude;
+use super::super::pagination::{Pages,PageStream};
 
 
+#[derive(Clone)]
 pub struct CountryGateway {
     gateway: Gateway
 }
@@ -16,8 +19,12 @@ impl CountryGateway {
         self.gateway.get("/countries", Options::empty())
     }
     
-    pub fn all_with(&self, options: Options) -> Result<Wrapper<Vec<Country>>, SportMonksError> {
-        self.gateway.get("/countries", options)
+    pub fn all_with(&self, options: Options<CountryInclude>) -> Result<Wrapper<Vec<Country>>, SportMonksError> {
+        self.gateway.get("/countries", options.untyped())
+    }
+
+    pub fn all_pages(&self, options: Options<CountryInclude>) -> Pages<Country> {
+        Pages::new(self.gateway.clone(), "/countries", options.untyped())
     }
     
     pub fn find(&self, id: i64) -> Result<Wrapper<Country>, SportMonksError> {
@@ -25,9 +32,42 @@ impl CountryGateway {
         self.gateway.get(&path, Options::empty())
     }
 
-    pub fn find_with(&self, id: i64, options: Options) -> Result<Wrapper<Country>, SportMonksError> {
+    pub fn find_with(&self, id: i64, options: Options<CountryInclude>) -> Result<Wrapper<Country>, SportMonksError> {
+        let path = format!("/countries/{}", id);
+        self.gateway.get(&path, options.untyped())
+    }
+}
+
+#[derive(Clone)]
+pub struct AsyncCountryGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncCountryGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncCountryGateway {
+        AsyncCountryGateway { gateway }
+    }
+
+    pub fn all(&self) -> SportMonksFuture<Wrapper<Vec<Country>>> {
+        self.gateway.get("/countries", Options::empty())
+    }
+    
+    pub fn all_with(&self, options: Options<CountryInclude>) -> SportMonksFuture<Wrapper<Vec<Country>>> {
+        self.gateway.get("/countries", options.untyped())
+    }
+
+    pub fn all_pages(&self, options: Options<CountryInclude>) -> PageStream<Country> {
+        PageStream::new(self.gateway.clone(), "/countries", options.untyped())
+    }
+    
+    pub fn find(&self, id: i64) -> SportMonksFuture<Wrapper<Country>> {
+        let path = format!("/countries/{}", id);
+        self.gateway.get(&path, Options::empty())
+    }
+
+    pub fn find_with(&self, id: i64, options: Options<CountryInclude>) -> SportMonksFuture<Wrapper<Country>> {
         let path = format!("/countries/{}", id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
     }
 }
 
@@ -1,10 +1,13 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Fixture,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::FixtureInclude;
+use super::super::pagination::{Pages,PageStream};
 use chrono::{Date, Utc};
 
 
 
+#[derive(Clone)]
 pub struct FixtureGateway {
     gateway: Gateway
 }
@@ -18,9 +21,9 @@ impl FixtureGateway {
         self.gateway.get(&path, Options::empty())
     }
     
-    pub fn find_with(&self, id: i64, options: Options) -> Result<Wrapper<Fixture>, SportMonksError> {
+    pub fn find_with(&self, id: i64, options: Options<FixtureInclude>) -> Result<Wrapper<Fixture>, SportMonksError> {
         let path = format!("/fixtures/{}", id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
     }
 
     pub fn on(&self, date: Date<Utc>) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
@@ -28,9 +31,14 @@ impl FixtureGateway {
         self.gateway.get(&path, Options::empty())
     }
 
-    pub fn on_with(&self, date: Date<Utc>, options: Options) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
+    pub fn on_with(&self, date: Date<Utc>, options: Options<FixtureInclude>) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
         let path = format!("/fixtures/date/{}", date.format("%Y-%m-%d"));
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn on_pages(&self, date: Date<Utc>, options: Options<FixtureInclude>) -> Pages<Fixture> {
+        let path = format!("/fixtures/date/{}", date.format("%Y-%m-%d"));
+        Pages::new(self.gateway.clone(), &path, options.untyped())
     }
     
     pub fn between(&self, start: Date<Utc>, end: Date<Utc>) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
@@ -38,9 +46,14 @@ impl FixtureGateway {
         self.gateway.get(&path, Options::empty())
     }   
     
-    pub fn between_with(&self, start: Date<Utc>, end: Date<Utc>, options: Options) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
+    pub fn between_with(&self, start: Date<Utc>, end: Date<Utc>, options: Options<FixtureInclude>) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
         let path = format!("/fixtures/between/{}/{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"));
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn between_pages(&self, start: Date<Utc>, end: Date<Utc>, options: Options<FixtureInclude>) -> Pages<Fixture> {
+        let path = format!("/fixtures/between/{}/{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"));
+        Pages::new(self.gateway.clone(), &path, options.untyped())
     }
     
     pub fn team_between(&self, team_id: i64, start: Date<Utc>, end: Date<Utc>) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
@@ -48,19 +61,99 @@ impl FixtureGateway {
         self.gateway.get(&path, Options::empty())
     }
 
-    pub fn team_between_with(&self, team_id: i64, start: Date<Utc>, end: Date<Utc>, options: Options) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
+    pub fn team_between_with(&self, team_id: i64, start: Date<Utc>, end: Date<Utc>, options: Options<FixtureInclude>) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
+        let path = format!("/fixtures/between/{}/{}/{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"), team_id);
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn team_between_pages(&self, team_id: i64, start: Date<Utc>, end: Date<Utc>, options: Options<FixtureInclude>) -> Pages<Fixture> {
         let path = format!("/fixtures/between/{}/{}/{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"), team_id);
-        self.gateway.get(&path, options)
+        Pages::new(self.gateway.clone(), &path, options.untyped())
     }
     
     pub fn filter(&self, fixture_ids: &[i64]) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
         self.filter_with(fixture_ids, Options::empty())
     }
     
-    pub fn filter_with(&self, fixture_ids: &[i64], options: Options) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
+    pub fn filter_with(&self, fixture_ids: &[i64], options: Options<FixtureInclude>) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
         let list_of_ids: Vec<String> = fixture_ids.iter().map(ToString::to_string).collect();
         let path = format!("/fixtures/multi/{}", list_of_ids.join(","));
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
+    }
+}
+
+#[derive(Clone)]
+pub struct AsyncFixtureGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncFixtureGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncFixtureGateway {
+        AsyncFixtureGateway { gateway }
+    }
+    pub fn find(&self, id: i64) -> SportMonksFuture<Wrapper<Fixture>> {
+        let path = format!("/fixtures/{}", id);
+        self.gateway.get(&path, Options::empty())
+    }
+    
+    pub fn find_with(&self, id: i64, options: Options<FixtureInclude>) -> SportMonksFuture<Wrapper<Fixture>> {
+        let path = format!("/fixtures/{}", id);
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn on(&self, date: Date<Utc>) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        let path = format!("/fixtures/date/{}", date.format("%Y-%m-%d"));
+        self.gateway.get(&path, Options::empty())
+    }
+
+    pub fn on_with(&self, date: Date<Utc>, options: Options<FixtureInclude>) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        let path = format!("/fixtures/date/{}", date.format("%Y-%m-%d"));
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn on_pages(&self, date: Date<Utc>, options: Options<FixtureInclude>) -> PageStream<Fixture> {
+        let path = format!("/fixtures/date/{}", date.format("%Y-%m-%d"));
+        PageStream::new(self.gateway.clone(), &path, options.untyped())
+    }
+    
+    pub fn between(&self, start: Date<Utc>, end: Date<Utc>) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        let path = format!("/fixtures/between/{}/{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"));
+        self.gateway.get(&path, Options::empty())
+    }   
+    
+    pub fn between_with(&self, start: Date<Utc>, end: Date<Utc>, options: Options<FixtureInclude>) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        let path = format!("/fixtures/between/{}/{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"));
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn between_pages(&self, start: Date<Utc>, end: Date<Utc>, options: Options<FixtureInclude>) -> PageStream<Fixture> {
+        let path = format!("/fixtures/between/{}/{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"));
+        PageStream::new(self.gateway.clone(), &path, options.untyped())
+    }
+    
+    pub fn team_between(&self, team_id: i64, start: Date<Utc>, end: Date<Utc>) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        let path = format!("/fixtures/between/{}/{}/{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"), team_id);
+        self.gateway.get(&path, Options::empty())
+    }
+
+    pub fn team_between_with(&self, team_id: i64, start: Date<Utc>, end: Date<Utc>, options: Options<FixtureInclude>) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        let path = format!("/fixtures/between/{}/{}/{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"), team_id);
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn team_between_pages(&self, team_id: i64, start: Date<Utc>, end: Date<Utc>, options: Options<FixtureInclude>) -> PageStream<Fixture> {
+        let path = format!("/fixtures/between/{}/{}/{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"), team_id);
+        PageStream::new(self.gateway.clone(), &path, options.untyped())
+    }
+    
+    pub fn filter(&self, fixture_ids: &[i64]) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        self.filter_with(fixture_ids, Options::empty())
+    }
+    
+    pub fn filter_with(&self, fixture_ids: &[i64], options: Options<FixtureInclude>) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        let list_of_ids: Vec<String> = fixture_ids.iter().map(ToString::to_string).collect();
+        let path = format!("/fixtures/multi/{}", list_of_ids.join(","));
+        self.gateway.get(&path, options.untyped())
     }
 }
 
@@ -70,7 +163,9 @@ mod tests {
     use mockito::mock;
     use std::fs;
     use std::path::Path;
-    use chrono::TimeZone;
+    use models::{FixtureStatus, Side};
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+    use tokio::runtime::Runtime;
 
     #[test]
     fn it_finds_fixtures_by_id() {
@@ -113,10 +208,11 @@ mod tests {
         assert_eq!(fixture.scores.ft_score, None);
         assert_eq!(fixture.scores.et_score, None);
 
-        assert_eq!(&fixture.time.status, "NS");
-        assert_eq!(fixture.time.starting_at.date_time, "2019-02-20 20:00:00");
-        assert_eq!(fixture.time.starting_at.date, "2019-02-20");
-        assert_eq!(fixture.time.starting_at.time, "20:00:00");
+        assert_eq!(fixture.time.status, FixtureStatus::NotStarted);
+        assert!(!fixture.is_live() && !fixture.is_finished());
+        assert_eq!(fixture.time.starting_at.date_time, Utc.with_ymd_and_hms(2019, 2, 20, 20, 0, 0).unwrap());
+        assert_eq!(fixture.time.starting_at.date, NaiveDate::from_ymd_opt(2019, 2, 20).unwrap());
+        assert_eq!(fixture.time.starting_at.time, NaiveTime::from_hms_opt(20, 0, 0).unwrap());
         assert_eq!(fixture.time.starting_at.timestamp, 1550692800);
         assert_eq!(fixture.time.starting_at.timezone, "UTC");
 
@@ -142,6 +238,85 @@ mod tests {
 
     }
 
+    #[test]
+    fn it_finds_fixtures_by_id_asynchronously() {
+        let body = fs::read_to_string(Path::new("src/support/fixtures/find.json")).expect("Fixtures:");
+        let m = mock("GET", "/fixtures/11414776?api_token=1234")
+          .with_status(200)
+          .with_body(body)
+          .create();
+
+        let instance = AsyncFixtureGateway::new(AsyncGateway::new("1234".into()));
+        let mut runtime = Runtime::new().unwrap();
+        let fixture = runtime.block_on(instance.find(11414776)).unwrap().data;
+
+        m.assert();
+
+        assert_eq!(fixture.id, 11414776);
+        assert_eq!(fixture.localteam_id, 67);
+        assert_eq!(fixture.visitorteam_id, 9);
+    }
+
+    #[test]
+    fn it_compiles_typed_includes_to_the_same_query() {
+        let body = fs::read_to_string(Path::new("src/support/fixtures/find_with.json")).expect("Fixtures:");
+        let m = mock("GET", "/fixtures/11414776?api_token=1234&include=visitorTeam%2ClocalTeam")
+          .with_status(200)
+          .with_body(body)
+          .create();
+
+        let instance = FixtureGateway::new(Gateway::new("1234".into()));
+        let opts = Options::builder().with(FixtureInclude::VisitorTeam).with(FixtureInclude::LocalTeam);
+        let fixture = instance.find_with(11414776, opts).unwrap().data;
+
+        m.assert();
+        assert_eq!(fixture.local_team.unwrap().name, "Schalke 04");
+        assert_eq!(fixture.visitor_team.unwrap().name, "Manchester City");
+    }
+
+    #[test]
+    fn it_merges_raw_and_typed_includes_into_one_param() {
+        let body = fs::read_to_string(Path::new("src/support/fixtures/find_with.json")).expect("Fixtures:");
+        let m = mock("GET", "/fixtures/11414776?api_token=1234&include=lineup%2ClocalTeam%2CvisitorTeam%2Cbench")
+          .with_status(200)
+          .with_body(body)
+          .create();
+
+        let instance = FixtureGateway::new(Gateway::new("1234".into()));
+        let opts = Options::builder().include(&["lineup"]).with(FixtureInclude::LocalTeam).include(&["visitorTeam", "bench"]);
+        assert_eq!(opts.requested_includes(), vec!["lineup", "localTeam", "visitorTeam", "bench"]);
+        let result = instance.find_with(11414776, opts);
+
+        m.assert();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_reports_and_enforces_requested_includes() {
+        let body = fs::read_to_string(Path::new("src/support/fixtures/find_with.json")).expect("Fixtures:");
+        let m = mock("GET", "/fixtures/11414776?api_token=1234&include=localTeam%2Clineup")
+          .with_status(200)
+          .with_body(body)
+          .expect(2)
+          .create();
+
+        let instance = FixtureGateway::new(Gateway::new("1234".into()));
+        let opts = Options::builder().with(FixtureInclude::LocalTeam).with(FixtureInclude::Lineup);
+        let response = instance.find_with(11414776, opts.clone()).unwrap();
+        let strict = instance.find_with(11414776, opts.strict_includes());
+
+        m.assert();
+        assert!(response.includes.is_present("localTeam"));
+        assert_eq!(response.includes.missing, vec!["lineup".to_string()]);
+        match strict.unwrap_err() {
+            SportMonksError::MissingIncludes { endpoint, missing } => {
+                assert_eq!(endpoint, "/fixtures/11414776");
+                assert_eq!(missing, vec!["lineup".to_string()]);
+            },
+            other => panic!("unexpected error {:?}", other)
+        }
+    }
+
     #[test]
     fn it_finds_fixtures_by_id_with_details() {
         let body = fs::read_to_string(Path::new("src/support/fixtures/find_with.json")).expect("Fixtures:");
@@ -206,7 +381,7 @@ mod tests {
         let substitutions = result.data[0].substitutions.clone().unwrap();
         assert_eq!(substitutions.len(), 4);
         assert_eq!(substitutions[0].id, 1685506004);
-        assert_eq!(&substitutions[0].team_id, "2379");
+        assert_eq!(substitutions[0].team_id, 2379);
         assert_eq!(&substitutions[0].kind, "subst");
         assert_eq!(substitutions[0].fixture_id, 1685506);
         assert_eq!(substitutions[0].player_in_id, 12668);
@@ -220,7 +395,7 @@ mod tests {
         let goals = result.data[0].goals.clone().unwrap();
         assert_eq!(goals.len(), 2);
         assert_eq!(goals[0].id, 1685506001);
-        assert_eq!(&goals[0].team_id, "2379");
+        assert_eq!(goals[0].team_id, 2379);
         assert_eq!(&goals[0].kind, "goal");
         assert_eq!(goals[0].fixture_id, 1685506);
         assert_eq!(goals[0].player_id, Some(28212));
@@ -235,7 +410,16 @@ mod tests {
         let cards = result.data[0].cards.clone().unwrap();
         assert_eq!(cards.len(), 4);
         assert_eq!(cards[0].id, 1685506003);
-        assert_eq!(&cards[0].team_id, "2345");
+        assert_eq!(cards[0].team_id, 2345);
+        assert_eq!(result.data[0].side_of(cards[0].team_id), Some(Side::Away));
+        assert_eq!(result.data[0].side_of(goals[0].team_id), Some(Side::Home));
+        assert_eq!(result.data[0].side_of(1), None);
+
+        let timeline = result.data[0].timeline();
+        let last = timeline.last().unwrap();
+        assert_eq!(timeline.len(), goals.len() + cards.len() + substitutions.len());
+        assert!(timeline.windows(2).all(|pair| pair[0].minute <= pair[1].minute));
+        assert_eq!((last.score.home, last.score.away), (result.data[0].scores.localteam_score, result.data[0].scores.visitorteam_score));
         assert_eq!(&cards[0].kind, "yellowcard");
         assert_eq!(cards[0].fixture_id, 1685506);
         assert_eq!(cards[0].player_id, 24430);
@@ -246,7 +430,7 @@ mod tests {
 
         let events = result.data[0].events.clone().unwrap();
         assert_eq!(events[0].id, 1685506001);
-        assert_eq!(&events[0].team_id, "2379");
+        assert_eq!(events[0].team_id, 2379);
         assert_eq!(&events[0].kind, "goal");
         assert_eq!(events[0].fixture_id, 1685506);
         assert_eq!(events[0].player_id, 28212);
@@ -420,9 +604,7 @@ mod tests {
         let highlights = result.data[0].highlights.clone().unwrap();
         assert_eq!(highlights[0].fixture_id, 8801067);
         assert_eq!(&highlights[0].location, "https://cc.sporttube.com/embed/gq1CCCG");
-        assert_eq!(&highlights[0].created_at.date, "2018-02-13 20:34:44.000000");
-        assert_eq!(highlights[0].created_at.timezone_type, 3);
-        assert_eq!(&highlights[0].created_at.timezone, "UTC");
+        assert_eq!(highlights[0].created_at, Utc.with_ymd_and_hms(2018, 2, 13, 20, 34, 44).unwrap());
 
         let stage = result.data[0].stage.clone().unwrap();
         assert_eq!(stage.id, 198337);
@@ -462,9 +644,7 @@ mod tests {
         assert_eq!(odds[0].bookmaker[0].odds[0].total, None);
         assert_eq!(odds[0].bookmaker[0].odds[0].bookmaker_event_id, None);
 
-        assert_eq!(&odds[0].bookmaker[0].odds[0].last_update.date, "2018-02-13 21:25:28.000000");
-        assert_eq!(odds[0].bookmaker[0].odds[0].last_update.timezone_type, 3);
-        assert_eq!(&odds[0].bookmaker[0].odds[0].last_update.timezone, "UTC");
+        assert_eq!(odds[0].bookmaker[0].odds[0].last_update, Utc.with_ymd_and_hms(2018, 2, 13, 21, 25, 28).unwrap());
 
         let flat_odds = result.data[0].flat_odds.clone().unwrap();
         assert_eq!(flat_odds[0].bookmaker_id, 25679219);
@@ -486,7 +666,7 @@ mod tests {
         assert_eq!(&local_coach.firstname, "Raphaël");
         assert_eq!(&local_coach.lastname, "Wicky");
         assert_eq!(&local_coach.nationality, "Switzerland");
-        assert_eq!(&local_coach.birthdate, "26/04/1977");
+        assert_eq!(local_coach.birthdate, NaiveDate::from_ymd_opt(1977, 4, 26));
         assert_eq!(&local_coach.birthcountry, "Switzerland");
         assert_eq!(&local_coach.birthplace, "Leuggern");
         assert_eq!(&local_coach.image_path, "https://cdn.sportmonks.com/images/soccer/players/26/407770.png");
@@ -500,7 +680,7 @@ mod tests {
         assert_eq!(&visitor_coach.firstname, "Josep");
         assert_eq!(&visitor_coach.lastname, "Guardiola i Sala");
         assert_eq!(&visitor_coach.nationality, "Spain");
-        assert_eq!(&visitor_coach.birthdate, "18/01/1971");
+        assert_eq!(visitor_coach.birthdate, NaiveDate::from_ymd_opt(1971, 1, 18));
         assert_eq!(&visitor_coach.birthcountry, "Spain");
         assert_eq!(&visitor_coach.birthplace, "Santpedor");
         assert_eq!(&visitor_coach.image_path, "https://cdn.sportmonks.com/images/soccer/players/1/455361.png");
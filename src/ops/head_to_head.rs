@@ -1,9 +1,12 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Fixture,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::FixtureInclude;
+use super::super::pagination::{Pages,PageStream};
 
 
 
+#[derive(Clone)]
 pub struct HeadToHeadGateway {
     gateway: Gateway
 }
@@ -17,9 +20,39 @@ impl HeadToHeadGateway {
         self.gateway.get(&path, Options::empty())
     }
     
-    pub fn find_with(&self, team_one_id: i64, team_two_id: i64, options: Options) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
+    pub fn find_with(&self, team_one_id: i64, team_two_id: i64, options: Options<FixtureInclude>) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
         let path = format!("/head2head/{}/{}", team_one_id, team_two_id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn find_pages(&self, team_one_id: i64, team_two_id: i64, options: Options<FixtureInclude>) -> Pages<Fixture> {
+        let path = format!("/head2head/{}/{}", team_one_id, team_two_id);
+        Pages::new(self.gateway.clone(), &path, options.untyped())
+    }
+}
+
+#[derive(Clone)]
+pub struct AsyncHeadToHeadGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncHeadToHeadGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncHeadToHeadGateway {
+        AsyncHeadToHeadGateway { gateway }
+    }
+    pub fn find(&self, team_one_id: i64, team_two_id: i64) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        let path = format!("/head2head/{}/{}", team_one_id, team_two_id);
+        self.gateway.get(&path, Options::empty())
+    }
+    
+    pub fn find_with(&self, team_one_id: i64, team_two_id: i64, options: Options<FixtureInclude>) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        let path = format!("/head2head/{}/{}", team_one_id, team_two_id);
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn find_pages(&self, team_one_id: i64, team_two_id: i64, options: Options<FixtureInclude>) -> PageStream<Fixture> {
+        let path = format!("/head2head/{}/{}", team_one_id, team_two_id);
+        PageStream::new(self.gateway.clone(), &path, options.untyped())
     }
 }
 
@@ -1,8 +1,11 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{League,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::LeagueInclude;
+use super::super::pagination::{Pages,PageStream};
 
 
+#[derive(Clone)]
 pub struct LeagueGateway {
     gateway: Gateway
 }
@@ -16,8 +19,12 @@ impl LeagueGateway {
         self.gateway.get("/leagues", Options::empty())
     }
     
-    pub fn all_with(&self, options: Options) -> Result<Wrapper<Vec<League>>, SportMonksError> {
-        self.gateway.get("/leagues", options)
+    pub fn all_with(&self, options: Options<LeagueInclude>) -> Result<Wrapper<Vec<League>>, SportMonksError> {
+        self.gateway.get("/leagues", options.untyped())
+    }
+
+    pub fn all_pages(&self, options: Options<LeagueInclude>) -> Pages<League> {
+        Pages::new(self.gateway.clone(), "/leagues", options.untyped())
     }
     
     pub fn find(&self, id: i64) -> Result<Wrapper<League>, SportMonksError> {
@@ -25,9 +32,42 @@ impl LeagueGateway {
         self.gateway.get(&path, Options::empty())
     }
 
-    pub fn find_with(&self, id: i64, options: Options) -> Result<Wrapper<League>, SportMonksError> {
+    pub fn find_with(&self, id: i64, options: Options<LeagueInclude>) -> Result<Wrapper<League>, SportMonksError> {
         let path = format!("/leagues/{}", id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
+    }
+}
+
+#[derive(Clone)]
+pub struct AsyncLeagueGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncLeagueGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncLeagueGateway {
+        AsyncLeagueGateway { gateway }
+    }
+
+    pub fn all(&self) -> SportMonksFuture<Wrapper<Vec<League>>> {
+        self.gateway.get("/leagues", Options::empty())
+    }
+    
+    pub fn all_with(&self, options: Options<LeagueInclude>) -> SportMonksFuture<Wrapper<Vec<League>>> {
+        self.gateway.get("/leagues", options.untyped())
+    }
+
+    pub fn all_pages(&self, options: Options<LeagueInclude>) -> PageStream<League> {
+        PageStream::new(self.gateway.clone(), "/leagues", options.untyped())
+    }
+    
+    pub fn find(&self, id: i64) -> SportMonksFuture<Wrapper<League>> {
+        let path = format!("/leagues/{}", id);
+        self.gateway.get(&path, Options::empty())
+    }
+
+    pub fn find_with(&self, id: i64, options: Options<LeagueInclude>) -> SportMonksFuture<Wrapper<League>> {
+        let path = format!("/leagues/{}", id);
+        self.gateway.get(&path, options.untyped())
     }
 }
 
@@ -38,6 +78,21 @@ mod tests {
     use std::fs;
     use std::path::Path;
 
+    #[test]
+    fn it_iterates_over_every_page_of_leagues() {
+        let body = fs::read_to_string(Path::new("src/support/leagues/all.json")).expect("Fixtures:");
+        let m = mock("GET", "/leagues?api_token=1234")
+          .with_status(200)
+          .with_body(body)
+          .expect(1)
+          .create();
+
+        let instance = LeagueGateway::new(Gateway::new("1234".into()));
+        let leagues: Vec<League> = instance.all_pages(Options::empty()).items().map(Result::unwrap).collect();
+        m.assert();
+        assert_eq!(leagues.len(), 8);
+    }
+
     #[test]
     fn it_returns_all_the_leagues() {
         let body = fs::read_to_string(Path::new("src/support/leagues/all.json")).expect("Fixtures:");
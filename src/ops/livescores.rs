@@ -1,7 +1,11 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Fixture,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::FixtureInclude;
+use super::super::pagination::{Pages,PageStream};
+use super::super::live::LiveWatcher;
 
+#[derive(Clone)]
 pub struct LivescoreGateway {
     gateway: Gateway
 }
@@ -15,16 +19,56 @@ impl LivescoreGateway {
         self.gateway.get("/livescores", Options::empty())
     }
 
-    pub fn all_with(&self, options: Options) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
-        self.gateway.get("/livescores", options)
+    pub fn all_with(&self, options: Options<FixtureInclude>) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
+        self.gateway.get("/livescores", options.untyped())
+    }
+
+    pub fn all_pages(&self, options: Options<FixtureInclude>) -> Pages<Fixture> {
+        Pages::new(self.gateway.clone(), "/livescores", options.untyped())
     }
 
     pub fn now(&self) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
         self.gateway.get("/livescores/now", Options::empty())
     }
 
-    pub fn now_with(&self, options: Options) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
-        self.gateway.get("/livescores/now", options)
+    pub fn now_with(&self, options: Options<FixtureInclude>) -> Result<Wrapper<Vec<Fixture>>, SportMonksError> {
+        self.gateway.get("/livescores/now", options.untyped())
+    }
+
+    /// Watches the fixtures of `/livescores/now`, requesting them with `options` on every poll.
+    pub fn watch(&self, options: Options<FixtureInclude>) -> LiveWatcher {
+        LiveWatcher::new(LivescoreGateway::new(self.gateway.clone()), options)
+    }
+}
+
+#[derive(Clone)]
+pub struct AsyncLivescoreGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncLivescoreGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncLivescoreGateway {
+        AsyncLivescoreGateway { gateway }
+    }
+
+    pub fn all(&self) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        self.gateway.get("/livescores", Options::empty())
+    }
+
+    pub fn all_with(&self, options: Options<FixtureInclude>) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        self.gateway.get("/livescores", options.untyped())
+    }
+
+    pub fn all_pages(&self, options: Options<FixtureInclude>) -> PageStream<Fixture> {
+        PageStream::new(self.gateway.clone(), "/livescores", options.untyped())
+    }
+
+    pub fn now(&self) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        self.gateway.get("/livescores/now", Options::empty())
+    }
+
+    pub fn now_with(&self, options: Options<FixtureInclude>) -> SportMonksFuture<Wrapper<Vec<Fixture>>> {
+        self.gateway.get("/livescores/now", options.untyped())
     }
 }
 
@@ -34,6 +78,8 @@ mod tests {
     use mockito::mock;
     use std::fs;
     use std::path::Path;
+    use models::FixtureStatus;
+    use tokio::runtime::Runtime;
 
     #[test]
     fn it_returns_all_the_livescores_with_details() {
@@ -119,6 +165,81 @@ mod tests {
         assert!(fixtures[0].inplay.is_some());
     }
     
+    #[test]
+    fn it_returns_all_the_livescores_in_play_asynchronously() {
+        let body = fs::read_to_string(Path::new("src/support/livescores/now_with.json")).expect("Fixtures:");
+        let m = mock("GET", "/livescores/now?api_token=1234&include=inplay")
+          .with_status(200)
+          .with_body(body)
+          .create();
+
+        let instance = AsyncLivescoreGateway::new(AsyncGateway::new("1234".into()));
+        let mut runtime = Runtime::new().unwrap();
+        let response = runtime.block_on(instance.now_with(Options::builder().include(&["inplay"])));
+        m.assert();
+
+        assert!(response.is_ok());
+        let fixtures = response.unwrap().data;
+        assert!(fixtures[0].inplay.is_some());
+    }
+
+    #[test]
+    fn it_surfaces_api_errors_asynchronously() {
+        let m = mock("GET", "/livescores?api_token=1234")
+          .with_status(401)
+          .with_body(r#"{"error":{"message":"Unauthenticated.","code":401}}"#)
+          .create();
+
+        let instance = AsyncLivescoreGateway::new(AsyncGateway::new("1234".into()));
+        let mut runtime = Runtime::new().unwrap();
+        let error = runtime.block_on(instance.all()).unwrap_err();
+        m.assert();
+
+        assert_eq!(error.code(), 401);
+        assert_eq!(&error.message(), "Unauthenticated.");
+    }
+    
+    #[test]
+    fn it_sends_typed_filters() {
+        let body = fs::read_to_string(Path::new("src/support/livescores/now_with_regression.json")).expect("Fixtures:");
+        let m = mock("GET", "/livescores/now?api_token=1234&leagues=2%2C5%2C8&status=LIVE%2CHT&tz=Europe%2FAmsterdam")
+          .with_status(200)
+          .with_body(body)
+          .create();
+
+        let instance = LivescoreGateway::new(Gateway::new("1234".into()));
+        let opts = Options::builder()
+            .leagues(&[2, 5, 8])
+            .status(&[FixtureStatus::Live, FixtureStatus::HalfTime])
+            .timezone("Europe/Amsterdam");
+        let result = instance.now_with(opts);
+        m.assert();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_refuses_malformed_filters_without_sending_them() {
+        let m = mock("GET", "/livescores/now?api_token=1234&leagues=&tz=Mars%2FOlympus")
+          .with_status(200)
+          .with_body("{}")
+          .expect(0)
+          .create();
+
+        let instance = LivescoreGateway::new(Gateway::new("1234".into()));
+        let opts = Options::builder().leagues(&[]).timezone("Mars/Olympus");
+        let error = instance.now_with(opts).unwrap_err();
+        m.assert();
+
+        match error {
+            SportMonksError::InvalidOptions(problems) => assert_eq!(problems, vec![
+                "leagues requires at least one id".to_string(),
+                "unknown timezone \"Mars/Olympus\"".to_string()
+            ]),
+            other => panic!("unexpected error {:?}", other)
+        }
+    }
+
     #[test]
     fn it_works_with_regression_test_one() {
     let body = fs::read_to_string(Path::new("src/support/livescores/now_with_regression.json")).expect("Fixtures:");
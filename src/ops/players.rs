@@ -1,8 +1,10 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Player,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::PlayerInclude;
 
 
+#[derive(Clone)]
 pub struct PlayerGateway {
     gateway: Gateway
 }
@@ -16,9 +18,29 @@ impl PlayerGateway {
         self.find_with(id, Options::empty())
     }
 
-    pub fn find_with(&self, id: i64, options: Options) -> Result<Wrapper<Player>, SportMonksError> {
+    pub fn find_with(&self, id: i64, options: Options<PlayerInclude>) -> Result<Wrapper<Player>, SportMonksError> {
         let path = format!("/players/{}", id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
+    }
+}
+
+#[derive(Clone)]
+pub struct AsyncPlayerGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncPlayerGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncPlayerGateway {
+        AsyncPlayerGateway { gateway }
+    }
+
+    pub fn find(&self, id: i64) -> SportMonksFuture<Wrapper<Player>> {
+        self.find_with(id, Options::empty())
+    }
+
+    pub fn find_with(&self, id: i64, options: Options<PlayerInclude>) -> SportMonksFuture<Wrapper<Player>> {
+        let path = format!("/players/{}", id);
+        self.gateway.get(&path, options.untyped())
     }
 }
 
@@ -1,8 +1,11 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Season,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::SeasonInclude;
+use super::super::pagination::{Pages,PageStream};
 
 
+#[derive(Clone)]
 pub struct SeasonGateway {
     gateway: Gateway
 }
@@ -16,8 +19,12 @@ impl SeasonGateway {
         self.gateway.get("/seasons", Options::empty())
     }
     
-    pub fn all_with(&self, options: Options) -> Result<Wrapper<Vec<Season>>, SportMonksError> {
-        self.gateway.get("/seasons", options)
+    pub fn all_with(&self, options: Options<SeasonInclude>) -> Result<Wrapper<Vec<Season>>, SportMonksError> {
+        self.gateway.get("/seasons", options.untyped())
+    }
+
+    pub fn all_pages(&self, options: Options<SeasonInclude>) -> Pages<Season> {
+        Pages::new(self.gateway.clone(), "/seasons", options.untyped())
     }
     
     pub fn find(&self, id: i64) -> Result<Wrapper<Season>, SportMonksError> {
@@ -25,9 +32,42 @@ impl SeasonGateway {
         self.gateway.get(&path, Options::empty())
     }
 
-    pub fn find_with(&self, id: i64, options: Options) -> Result<Wrapper<Season>, SportMonksError> {
+    pub fn find_with(&self, id: i64, options: Options<SeasonInclude>) -> Result<Wrapper<Season>, SportMonksError> {
+        let path = format!("/seasons/{}", id);
+        self.gateway.get(&path, options.untyped())
+    }
+}
+
+#[derive(Clone)]
+pub struct AsyncSeasonGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncSeasonGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncSeasonGateway {
+        AsyncSeasonGateway { gateway }
+    }
+
+    pub fn all(&self) -> SportMonksFuture<Wrapper<Vec<Season>>> {
+        self.gateway.get("/seasons", Options::empty())
+    }
+    
+    pub fn all_with(&self, options: Options<SeasonInclude>) -> SportMonksFuture<Wrapper<Vec<Season>>> {
+        self.gateway.get("/seasons", options.untyped())
+    }
+
+    pub fn all_pages(&self, options: Options<SeasonInclude>) -> PageStream<Season> {
+        PageStream::new(self.gateway.clone(), "/seasons", options.untyped())
+    }
+    
+    pub fn find(&self, id: i64) -> SportMonksFuture<Wrapper<Season>> {
+        let path = format!("/seasons/{}", id);
+        self.gateway.get(&path, Options::empty())
+    }
+
+    pub fn find_with(&self, id: i64, options: Options<SeasonInclude>) -> SportMonksFuture<Wrapper<Season>> {
         let path = format!("/seasons/{}", id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
     }
 }
 
@@ -37,6 +77,8 @@ mod tests {
     use mockito::mock;
     use std::fs;
     use std::path::Path;
+    use models::FixtureStatus;
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
 
     #[test]
     fn it_returns_all_the_seasons() {
@@ -478,10 +520,11 @@ mod tests {
         assert_eq!(results[0].scores.ft_score, Some("0-0".to_string()));
         assert_eq!(results[0].scores.et_score, Some("0-2".to_string()));
 
-        assert_eq!(&results[0].time.status, "AET");
-        assert_eq!(results[0].time.starting_at.date_time, "2018-06-26 15:00:00");
-        assert_eq!(results[0].time.starting_at.date, "2018-06-26");
-        assert_eq!(results[0].time.starting_at.time, "15:00:00");
+        assert_eq!(results[0].time.status, FixtureStatus::AfterExtraTime);
+        assert!(results[0].is_finished());
+        assert_eq!(results[0].time.starting_at.date_time, Utc.with_ymd_and_hms(2018, 6, 26, 15, 0, 0).unwrap());
+        assert_eq!(results[0].time.starting_at.date, NaiveDate::from_ymd_opt(2018, 6, 26).unwrap());
+        assert_eq!(results[0].time.starting_at.time, NaiveTime::from_hms_opt(15, 0, 0).unwrap());
         assert_eq!(results[0].time.starting_at.timestamp, 1530025200);
         assert_eq!(results[0].time.starting_at.timezone, "UTC");
 
@@ -542,8 +585,8 @@ mod tests {
         assert_eq!(rounds[0].league_id, 2);
         assert_eq!(rounds[0].season_id, 12950);
         assert_eq!(rounds[0].stage_id, 7416759);
-        assert_eq!(&rounds[0].start, "2018-09-18");
-        assert_eq!(&rounds[0].end, "2018-09-19");   
+        assert_eq!(rounds[0].start, NaiveDate::from_ymd_opt(2018, 9, 18));
+        assert_eq!(rounds[0].end, NaiveDate::from_ymd_opt(2018, 9, 19));
     }
     
     #[test]
@@ -640,10 +683,10 @@ mod tests {
         assert_eq!(results[0].scores.ft_score, None);
         assert_eq!(results[0].scores.et_score, None);
 
-        assert_eq!(&results[0].time.status, "NS");
-        assert_eq!(results[0].time.starting_at.date_time, "2019-02-20 20:00:00");
-        assert_eq!(results[0].time.starting_at.date, "2019-02-20");
-        assert_eq!(results[0].time.starting_at.time, "20:00:00");
+        assert_eq!(results[0].time.status, FixtureStatus::NotStarted);
+        assert_eq!(results[0].time.starting_at.date_time, Utc.with_ymd_and_hms(2019, 2, 20, 20, 0, 0).unwrap());
+        assert_eq!(results[0].time.starting_at.date, NaiveDate::from_ymd_opt(2019, 2, 20).unwrap());
+        assert_eq!(results[0].time.starting_at.time, NaiveTime::from_hms_opt(20, 0, 0).unwrap());
         assert_eq!(results[0].time.starting_at.timestamp, 1550692800);
         assert_eq!(results[0].time.starting_at.timezone, "UTC");
 
@@ -1,8 +1,10 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Standing,LiveStanding,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::StandingInclude;
 
 
+#[derive(Clone)]
 pub struct StandingGateway {
     gateway: Gateway
 }
@@ -16,9 +18,9 @@ impl StandingGateway {
         self.find_with(season_id, Options::empty())
     }
     
-    pub fn find_with(&self, season_id: i64, options: Options) -> Result<Wrapper<Vec<Standing>>, SportMonksError> {
+    pub fn find_with(&self, season_id: i64, options: Options<StandingInclude>) -> Result<Wrapper<Vec<Standing>>, SportMonksError> {
         let path = format!("/standings/season/{}", season_id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
     }
 
     pub fn live(&self, id: i64) -> Result<Wrapper<Vec<LiveStanding>>, SportMonksError> {
@@ -27,6 +29,31 @@ impl StandingGateway {
     }
 }
 
+#[derive(Clone)]
+pub struct AsyncStandingGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncStandingGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncStandingGateway {
+        AsyncStandingGateway { gateway }
+    }
+
+    pub fn find(&self, season_id: i64) -> SportMonksFuture<Wrapper<Vec<Standing>>> {
+        self.find_with(season_id, Options::empty())
+    }
+    
+    pub fn find_with(&self, season_id: i64, options: Options<StandingInclude>) -> SportMonksFuture<Wrapper<Vec<Standing>>> {
+        let path = format!("/standings/season/{}", season_id);
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn live(&self, id: i64) -> SportMonksFuture<Wrapper<Vec<LiveStanding>>> {
+        let path = format!("/standings/season/live/{}", id);
+        self.gateway.get(&path, Options::empty())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
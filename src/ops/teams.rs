@@ -1,8 +1,10 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Team,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::TeamInclude;
 
 
+#[derive(Clone)]
 pub struct TeamGateway {
     gateway: Gateway
 }
@@ -16,18 +18,49 @@ impl TeamGateway {
         self.find_with(id, Options::empty())
     }
 
-    pub fn find_with(&self, id: i64, options: Options) -> Result<Wrapper<Team>, SportMonksError> {
+    pub fn find_with(&self, id: i64, options: Options<TeamInclude>) -> Result<Wrapper<Team>, SportMonksError> {
         let path = format!("/teams/{}", id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
     }
 
     pub fn of_season(&self, season_id: i64) -> Result<Wrapper<Vec<Team>>, SportMonksError> {
         self.of_season_with(season_id, Options::empty())
     }
 
-    pub fn of_season_with(&self, season_id: i64, options: Options) -> Result<Wrapper<Vec<Team>>, SportMonksError> {
+    pub fn of_season_with(&self, season_id: i64, options: Options<TeamInclude>) -> Result<Wrapper<Vec<Team>>, SportMonksError> {
         let path = format!("/teams/season/{}", season_id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
+    }
+
+
+}
+
+#[derive(Clone)]
+pub struct AsyncTeamGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncTeamGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncTeamGateway {
+        AsyncTeamGateway { gateway }
+    }
+    
+    pub fn find(&self, id: i64) -> SportMonksFuture<Wrapper<Team>> {
+        self.find_with(id, Options::empty())
+    }
+
+    pub fn find_with(&self, id: i64, options: Options<TeamInclude>) -> SportMonksFuture<Wrapper<Team>> {
+        let path = format!("/teams/{}", id);
+        self.gateway.get(&path, options.untyped())
+    }
+
+    pub fn of_season(&self, season_id: i64) -> SportMonksFuture<Wrapper<Vec<Team>>> {
+        self.of_season_with(season_id, Options::empty())
+    }
+
+    pub fn of_season_with(&self, season_id: i64, options: Options<TeamInclude>) -> SportMonksFuture<Wrapper<Vec<Team>>> {
+        let path = format!("/teams/season/{}", season_id);
+        self.gateway.get(&path, options.untyped())
     }
 
 
@@ -1,8 +1,10 @@
 use super::super::errors::SportMonksError;
 use super::super::models::{Season,Wrapper};
-use super::super::gateway::{Gateway,Options};
+use super::super::gateway::{Gateway,AsyncGateway,Options,SportMonksFuture};
+use super::super::includes::SeasonInclude;
 
 
+#[derive(Clone)]
 pub struct TopscorerGateway {
     gateway: Gateway
 }
@@ -17,18 +19,50 @@ impl TopscorerGateway {
         self.of_season_with(season_id, Options::empty())
     }
 
-    pub fn of_season_with(&self, season_id: i64, options: Options) -> Result<Wrapper<Season>, SportMonksError> {
+    pub fn of_season_with(&self, season_id: i64, options: Options<SeasonInclude>) -> Result<Wrapper<Season>, SportMonksError> {
         let path = format!("/topscorers/season/{}", season_id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
     }
     
     pub fn aggregated_of_season(&self, season_id: i64) -> Result<Wrapper<Season>, SportMonksError> {
         self.aggregated_of_season_with(season_id, Options::empty())
     }
 
-    pub fn aggregated_of_season_with(&self, season_id: i64, options: Options) -> Result<Wrapper<Season>, SportMonksError> {
+    pub fn aggregated_of_season_with(&self, season_id: i64, options: Options<SeasonInclude>) -> Result<Wrapper<Season>, SportMonksError> {
         let path = format!("/topscorers/season/{}/aggregated", season_id);
-        self.gateway.get(&path, options)
+        self.gateway.get(&path, options.untyped())
+    }
+
+
+}
+
+#[derive(Clone)]
+pub struct AsyncTopscorerGateway {
+    gateway: AsyncGateway
+}
+
+impl AsyncTopscorerGateway {
+    pub fn new(gateway: AsyncGateway) -> AsyncTopscorerGateway {
+        AsyncTopscorerGateway { gateway }
+    }
+    
+
+    pub fn of_season(&self, season_id: i64) -> SportMonksFuture<Wrapper<Season>> {
+        self.of_season_with(season_id, Options::empty())
+    }
+
+    pub fn of_season_with(&self, season_id: i64, options: Options<SeasonInclude>) -> SportMonksFuture<Wrapper<Season>> {
+        let path = format!("/topscorers/season/{}", season_id);
+        self.gateway.get(&path, options.untyped())
+    }
+    
+    pub fn aggregated_of_season(&self, season_id: i64) -> SportMonksFuture<Wrapper<Season>> {
+        self.aggregated_of_season_with(season_id, Options::empty())
+    }
+
+    pub fn aggregated_of_season_with(&self, season_id: i64, options: Options<SeasonInclude>) -> SportMonksFuture<Wrapper<Season>> {
+        let path = format!("/topscorers/season/{}/aggregated", season_id);
+        self.gateway.get(&path, options.untyped())
     }
 
 
@@ -0,0 +1,289 @@
+use super::errors::SportMonksError;
+use super::gateway::{Gateway, AsyncGateway, Options, SportMonksFuture};
+use super::models::{Meta, Wrapper};
+use serde::de::DeserializeOwned;
+use futures::{stream, Async, Future, Poll, Stream};
+use std::marker::PhantomData;
+use std::vec;
+
+/// Keeps track of which page to request next.
+struct Cursor {
+    path: String,
+    options: Options,
+    next_page: Option<i64>,
+    /// The page of the request in flight, to check against the page the API reports.
+    requested_page: i64,
+    max_pages: Option<u32>,
+    fetched: u32,
+}
+
+impl Cursor {
+    fn new(path: &str, options: Options) -> Cursor {
+        let next_page = Some(options.current_page().unwrap_or(1));
+        Cursor { path: path.to_string(), options, next_page, requested_page: 0, max_pages: None, fetched: 0 }
+    }
+
+    fn next_request(&mut self) -> Option<Options> {
+        let page = self.next_page.take()?;
+        if self.max_pages.is_some_and(|max_pages| self.fetched >= max_pages) {
+            return None;
+        }
+        self.fetched += 1;
+        self.requested_page = page;
+        if self.fetched == 1 {
+            Some(self.options.clone())
+        } else {
+            Some(self.options.clone().page(page))
+        }
+    }
+
+    /// Moves to the page after the one the API answered with, unless that was the last page.
+    ///
+    /// A page other than the one requested ends the pagination with an error, as a server
+    /// ignoring `page` would otherwise repeat the same records forever.
+    fn advance(&mut self, meta: &Option<Meta>) -> Result<(), SportMonksError> {
+        self.next_page = None;
+        let pagination = match meta.as_ref().and_then(|meta| meta.pagination.as_ref()) {
+            Some(pagination) => pagination,
+            None => return Ok(()),
+        };
+        if pagination.current_page != self.requested_page {
+            return Err(SportMonksError::UnexpectedPage {
+                endpoint: self.path.clone(),
+                requested: self.requested_page,
+                returned: pagination.current_page,
+            });
+        }
+        if pagination.current_page < pagination.total_pages {
+            self.next_page = Some(pagination.current_page + 1);
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the pages of a list endpoint, following `meta.pagination` until the last page.
+///
+/// Iteration stops after the first error. Use `items` to iterate over the records instead.
+pub struct Pages<T> {
+    gateway: Gateway,
+    cursor: Cursor,
+    phantom: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> Pages<T> {
+    pub fn new(gateway: Gateway, path: &str, options: Options) -> Pages<T> {
+        Pages { gateway, cursor: Cursor::new(path, options), phantom: PhantomData }
+    }
+
+    /// Stops after `max_pages` requests, even if the API reports more pages.
+    pub fn max_pages(mut self, max_pages: u32) -> Pages<T> {
+        self.cursor.max_pages = Some(max_pages);
+        self
+    }
+
+    pub fn items(self) -> Items<T> {
+        Items { pages: self, buffer: Vec::new().into_iter() }
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for Pages<T> {
+    type Item = Result<Wrapper<Vec<T>>, SportMonksError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let options = self.cursor.next_request()?;
+        let page = match self.gateway.get::<Vec<T>>(&self.cursor.path, options) {
+            Ok(page) => page,
+            Err(error) => return Some(Err(error)),
+        };
+        Some(self.cursor.advance(&page.meta).map(|_| page))
+    }
+}
+
+/// Iterator over the records of every page of a list endpoint.
+pub struct Items<T> {
+    pages: Pages<T>,
+    buffer: vec::IntoIter<T>,
+}
+
+impl<T: DeserializeOwned> Iterator for Items<T> {
+    type Item = Result<T, SportMonksError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffer.next() {
+                return Some(Ok(item));
+            }
+            match self.pages.next()? {
+                Ok(page) => self.buffer = page.data.into_iter(),
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+/// Stream over the pages of a list endpoint, the asynchronous counterpart of `Pages`.
+pub struct PageStream<T> {
+    gateway: AsyncGateway,
+    cursor: Cursor,
+    in_flight: Option<SportMonksFuture<Wrapper<Vec<T>>>>,
+}
+
+impl<T: DeserializeOwned + Send + 'static> PageStream<T> {
+    pub fn new(gateway: AsyncGateway, path: &str, options: Options) -> PageStream<T> {
+        PageStream { gateway, cursor: Cursor::new(path, options), in_flight: None }
+    }
+
+    /// Stops after `max_pages` requests, even if the API reports more pages.
+    pub fn max_pages(mut self, max_pages: u32) -> PageStream<T> {
+        self.cursor.max_pages = Some(max_pages);
+        self
+    }
+
+    pub fn items(self) -> impl Stream<Item = T, Error = SportMonksError> + Send {
+        self.map(|page| stream::iter_ok(page.data)).flatten()
+    }
+}
+
+impl<T: DeserializeOwned + Send + 'static> Stream for PageStream<T> {
+    type Item = Wrapper<Vec<T>>;
+    type Error = SportMonksError;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if self.in_flight.is_none() {
+            match self.cursor.next_request() {
+                Some(options) => self.in_flight = Some(self.gateway.get(&self.cursor.path, options)),
+                None => return Ok(Async::Ready(None)),
+            }
+        }
+        let result = match self.in_flight {
+            Some(ref mut future) => future.poll(),
+            None => unreachable!(),
+        };
+        match result {
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Ok(Async::Ready(page)) => {
+                self.in_flight = None;
+                self.cursor.advance(&page.meta)?;
+                Ok(Async::Ready(Some(page)))
+            },
+            Err(error) => {
+                self.in_flight = None;
+                self.cursor.next_page = None;
+                Err(error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use models::Season;
+    use mockito::{mock, Mock};
+    use reqwest::StatusCode;
+    use std::fs;
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+    use transport::{HttpRequest, HttpResponse, Transport};
+    use tokio::runtime::Runtime;
+
+    fn mock_page(query: &str, page: i64) -> Mock {
+        let body = fs::read_to_string(Path::new("src/support/seasons/all.json")).expect("Fixtures:")
+            .replace("\"current_page\":1", &format!("\"current_page\":{}", page));
+        mock("GET", format!("/seasons?api_token=1234{}", query).as_str())
+          .with_status(200)
+          .with_body(body)
+    }
+
+    /// Ignores `page` and always answers with the first of three pages.
+    #[derive(Default)]
+    struct FirstPageOnly {
+        requests: Mutex<u32>,
+    }
+
+    impl Transport for FirstPageOnly {
+        fn get(&self, _request: &HttpRequest) -> Result<HttpResponse, SportMonksError> {
+            *self.requests.lock().unwrap() += 1;
+            let body = fs::read_to_string(Path::new("src/support/seasons/all.json")).expect("Fixtures:");
+            Ok(HttpResponse { status: StatusCode::OK, retry_after: None, body })
+        }
+    }
+
+    #[test]
+    fn it_follows_the_pagination_until_the_last_page() {
+        let first = mock_page("", 1).create();
+        let second = mock_page("&page=2", 2).create();
+        let third = mock_page("&page=3", 3).create();
+
+        let pages = Pages::<Season>::new(Gateway::new("1234".into()), "/seasons", Options::empty());
+        let items = pages.items().collect::<Result<Vec<_>, _>>().unwrap();
+
+        first.assert();
+        second.assert();
+        third.assert();
+        assert_eq!(items.len(), 150);
+    }
+
+    #[test]
+    fn it_stops_at_the_page_cap() {
+        let first = mock_page("", 1).create();
+        let second = mock_page("&page=2", 2).create();
+        let third = mock_page("&page=3", 3).expect(0).create();
+
+        let pages = Pages::<Season>::new(Gateway::new("1234".into()), "/seasons", Options::empty()).max_pages(2);
+
+        assert_eq!(pages.count(), 2);
+        first.assert();
+        second.assert();
+        third.assert();
+    }
+
+    #[test]
+    fn it_stops_after_an_error() {
+        let first = mock_page("", 1).create();
+        let second = mock("GET", "/seasons?api_token=1234&page=2")
+          .with_status(500)
+          .with_body("oops")
+          .create();
+
+        let pages = Pages::<Season>::new(Gateway::new("1234".into()), "/seasons", Options::empty());
+        let results = pages.collect::<Vec<_>>();
+
+        first.assert();
+        second.assert();
+        assert_eq!(results.len(), 2);
+        assert!(results[1].is_err());
+    }
+
+    #[test]
+    fn it_streams_pages_asynchronously() {
+        let second = mock_page("&page=2", 2).create();
+        let third = mock_page("&page=3", 3).create();
+
+        let stream = PageStream::<Season>::new(AsyncGateway::new("1234".into()), "/seasons", Options::empty().page(2));
+        let mut runtime = Runtime::new().unwrap();
+        let items = runtime.block_on(stream.items().collect()).unwrap();
+
+        second.assert();
+        third.assert();
+        assert_eq!(items.len(), 100);
+    }
+
+    #[test]
+    fn it_stops_when_the_api_answers_another_page_than_requested() {
+        let transport = Arc::new(FirstPageOnly::default());
+        let gateway = Gateway::with_transport(transport.clone(), "1234".into(), "https://example.test/v2".into());
+
+        let pages = Pages::<Season>::new(gateway, "/seasons", Options::empty());
+
+        let results = pages.collect::<Vec<_>>();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().data.len(), 50);
+        match results[1] {
+            Err(SportMonksError::UnexpectedPage { requested, returned, .. }) => assert_eq!((requested, returned), (2, 1)),
+            ref other => panic!("unexpected result {:?}", other.as_ref().map(|page| page.data.len())),
+        }
+        assert_eq!(*transport.requests.lock().unwrap(), 2);
+    }
+}
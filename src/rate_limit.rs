@@ -0,0 +1,250 @@
+use super::errors::SportMonksError;
+use super::models::RequestLimit;
+use serde_json;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// What a `RateLimiter` does when a request arrives and the budget is spent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Exhaustion {
+    /// Wait until a token becomes available.
+    Block,
+    /// Fail straight away with a rate limit error.
+    Error,
+}
+
+/// Token bucket shared by every gateway of a client.
+///
+/// The budget is either configured by hand through `RateLimiter::new` or learnt from the
+/// `meta.plan.request_limit` field of the first response (`"2000,60"` meaning 2000 requests
+/// every 60 minutes) when built with `RateLimiter::from_plan`.
+#[derive(Debug)]
+pub struct RateLimiter {
+    bucket: Mutex<Bucket>,
+    exhaustion: Exhaustion,
+    auto_tune: bool,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_second: f64,
+    refilled_at: Instant,
+    tuned: bool,
+}
+
+impl RateLimiter {
+    /// Allows `requests` every `per`. A budget of zero requests is raised to one.
+    pub fn new(requests: u32, per: Duration) -> RateLimiter {
+        let capacity = f64::from(requests.max(1));
+        RateLimiter {
+            bucket: Mutex::new(Bucket::new(capacity, capacity / seconds(per))),
+            exhaustion: Exhaustion::Block,
+            auto_tune: false,
+        }
+    }
+
+    pub fn from_plan() -> RateLimiter {
+        RateLimiter {
+            bucket: Mutex::new(Bucket::new(f64::INFINITY, 0.0)),
+            exhaustion: Exhaustion::Block,
+            auto_tune: true,
+        }
+    }
+
+    pub fn on_exhaustion(mut self, exhaustion: Exhaustion) -> RateLimiter {
+        self.exhaustion = exhaustion;
+        self
+    }
+
+    pub fn exhaustion(&self) -> Exhaustion {
+        self.exhaustion
+    }
+
+    /// Takes a token, or tells how long to wait until one is available.
+    pub fn try_acquire(&self) -> Result<(), Duration> {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill();
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let missing = 1.0 - bucket.tokens;
+            Err(Duration::from_millis((missing / bucket.refill_per_second * 1000.0).ceil() as u64))
+        }
+    }
+
+    /// Takes a token, blocking the current thread or failing according to the exhaustion mode.
+    pub fn acquire(&self) -> Result<(), SportMonksError> {
+        loop {
+            match self.try_acquire() {
+                Ok(()) => return Ok(()),
+                Err(wait) => match self.exhaustion {
+                    Exhaustion::Block => thread::sleep(wait),
+                    Exhaustion::Error => return Err(exhausted(wait)),
+                }
+            }
+        }
+    }
+
+    /// Empties the bucket, e.g. after the API answered with a 429.
+    ///
+    /// A bucket still waiting for its plan budget never refills, so it is left as is; the
+    /// retry policy and the `Retry-After` of the 429 take care of the wait instead.
+    pub fn drain(&self) {
+        let mut bucket = self.bucket.lock().unwrap();
+        if bucket.refill_per_second <= 0.0 {
+            return;
+        }
+        bucket.refill();
+        bucket.tokens = bucket.tokens.min(0.0);
+    }
+
+    /// Reacts to a response: drains the bucket on 429s and learns the plan budget when auto-tuning.
+    pub fn observe(&self, status: u16, body: &str) {
+        if status == 429 {
+            self.drain();
+        } else if (200..300).contains(&status) && self.needs_plan() {
+            let request_limit = serde_json::from_str::<Envelope>(body).ok()
+                .and_then(|envelope| envelope.meta)
+                .and_then(|meta| meta.plan)
+                .and_then(|plan| plan.request_limit);
+            if let Some(request_limit) = request_limit {
+                self.tune(&request_limit);
+            }
+        }
+    }
+
+    pub fn needs_plan(&self) -> bool {
+        self.auto_tune && !self.bucket.lock().unwrap().tuned
+    }
+
+    /// Applies a `request_limit` such as `"2000,60"`. Malformed limits are ignored.
+    pub fn tune(&self, request_limit: &str) {
+        if let Ok(limit) = request_limit.parse::<RequestLimit>() {
+            if limit.requests == 0 || limit.minutes == 0 {
+                return;
+            }
+            let requests = f64::from(limit.requests);
+            let minutes = f64::from(limit.minutes);
+            let mut bucket = self.bucket.lock().unwrap();
+            bucket.refill();
+            bucket.capacity = requests;
+            bucket.tokens = bucket.tokens.min(requests);
+            bucket.refill_per_second = requests / (minutes * 60.0);
+            bucket.tuned = true;
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    meta: Option<EnvelopeMeta>,
+}
+
+#[derive(Deserialize)]
+struct EnvelopeMeta {
+    plan: Option<EnvelopePlan>,
+}
+
+#[derive(Deserialize)]
+struct EnvelopePlan {
+    request_limit: Option<String>,
+}
+
+impl Bucket {
+    fn new(capacity: f64, refill_per_second: f64) -> Bucket {
+        Bucket { capacity, tokens: capacity, refill_per_second, refilled_at: Instant::now(), tuned: false }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = seconds(now.duration_since(self.refilled_at));
+        self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        self.refilled_at = now;
+    }
+}
+
+pub fn exhausted(wait: Duration) -> SportMonksError {
+    SportMonksError::RateLimited { retry_after: Some(wait) }
+}
+
+fn seconds(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + f64::from(duration.subsec_nanos()) / 1_000_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_hands_out_the_configured_budget() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(3600));
+
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.try_acquire().is_ok());
+        let wait = limiter.try_acquire().unwrap_err();
+        assert!(wait > Duration::from_secs(1700) && wait <= Duration::from_secs(1800));
+    }
+
+    #[test]
+    fn it_errors_when_exhausted_in_error_mode() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(60)).on_exhaustion(Exhaustion::Error);
+
+        assert!(limiter.acquire().is_ok());
+        assert_eq!(limiter.acquire().unwrap_err().code(), 429);
+    }
+
+    #[test]
+    fn it_blocks_until_a_token_is_refilled() {
+        let limiter = RateLimiter::new(1, Duration::from_millis(20));
+
+        let started = Instant::now();
+        assert!(limiter.acquire().is_ok());
+        assert!(limiter.acquire().is_ok());
+        assert!(started.elapsed() >= Duration::from_millis(15));
+    }
+
+    #[test]
+    fn it_tunes_itself_from_the_plan_request_limit() {
+        let limiter = RateLimiter::from_plan();
+        assert!(limiter.needs_plan());
+
+        limiter.tune("garbage");
+        assert!(limiter.needs_plan());
+
+        limiter.tune("2,60");
+        assert!(!limiter.needs_plan());
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.try_acquire().is_err());
+    }
+
+    #[test]
+    fn it_does_not_drain_a_bucket_waiting_for_its_plan() {
+        let limiter = RateLimiter::from_plan();
+        limiter.observe(429, r#"{"error":{"message":"Too Many Attempts.","code":429}}"#);
+
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.needs_plan());
+    }
+
+    #[test]
+    fn it_raises_a_zero_budget_to_one_request() {
+        let limiter = RateLimiter::new(0, Duration::from_secs(60));
+
+        assert!(limiter.try_acquire().is_ok());
+        let wait = limiter.try_acquire().unwrap_err();
+        assert!(wait > Duration::from_secs(50) && wait <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn it_drains_the_bucket() {
+        let limiter = RateLimiter::new(10, Duration::from_secs(60));
+        limiter.drain();
+
+        assert!(limiter.try_acquire().is_err());
+    }
+}
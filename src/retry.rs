@@ -0,0 +1,166 @@
+use rand::{thread_rng, Rng};
+use reqwest::{Method, StatusCode};
+use std::cmp;
+use std::time::Duration;
+
+const DEFAULT_RETRYABLE_STATUSES: [u16; 6] = [408, 429, 500, 502, 503, 504];
+
+/// Decides whether a failed request is attempted again and how long to wait before doing so.
+///
+/// Delays grow exponentially from `base_delay` up to `max_delay`. With jitter enabled, the
+/// actual delay is picked uniformly between zero and that bound. Only idempotent methods
+/// (`GET` and `HEAD`) are ever retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retryable_statuses: Vec<u16>,
+}
+
+/// What came back from a single attempt, as far as the retry policy cares.
+pub enum Outcome<'a> {
+    Status(StatusCode, Option<Duration>),
+    Failure(&'a reqwest::Error),
+    /// A custom transport got no response and asked for the attempt to be repeated.
+    Unreachable,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            retryable_statuses: DEFAULT_RETRYABLE_STATUSES.to_vec(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    pub fn none() -> RetryPolicy {
+        RetryPolicy::default().max_attempts(1)
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = cmp::max(max_attempts, 1);
+        self
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> RetryPolicy {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> RetryPolicy {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> RetryPolicy {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn retry_on(mut self, statuses: &[u16]) -> RetryPolicy {
+        self.retryable_statuses = statuses.to_vec();
+        self
+    }
+
+    /// Upper bound of the wait before the attempt following `attempt` (1-based).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = cmp::min(attempt.saturating_sub(1), 16);
+        let bound = self.base_delay.checked_mul(1u32 << exponent).unwrap_or(self.max_delay);
+        cmp::min(bound, self.max_delay)
+    }
+
+    /// Returns how long to wait before retrying, or `None` when the outcome is final.
+    pub fn next_delay(&self, method: &Method, attempt: u32, outcome: Outcome) -> Option<Duration> {
+        if attempt >= self.max_attempts || !is_idempotent(method) {
+            return None;
+        }
+        let retry_after = match outcome {
+            Outcome::Status(status, retry_after) => {
+                if !self.retryable_statuses.contains(&status.as_u16()) {
+                    return None;
+                }
+                retry_after
+            },
+            Outcome::Failure(error) => {
+                if !(error.is_timeout() || error.is_http()) {
+                    return None;
+                }
+                None
+            },
+            Outcome::Unreachable => None,
+        };
+        let delay = self.delay(attempt);
+        Some(retry_after.map_or(delay, |retry_after| cmp::max(delay, retry_after)))
+    }
+
+    fn delay(&self, attempt: u32) -> Duration {
+        let bound = self.backoff(attempt);
+        if !self.jitter {
+            return bound;
+        }
+        let millis = bound.as_millis() as u64;
+        Duration::from_millis(thread_rng().gen_range(0, millis + 1))
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    *method == Method::GET || *method == Method::HEAD
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_grows_the_backoff_exponentially_up_to_the_cap() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(500));
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(40), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn it_keeps_jittered_delays_within_the_backoff() {
+        let policy = RetryPolicy::new().max_attempts(10).base_delay(Duration::from_millis(100));
+
+        for attempt in 1..6 {
+            let delay = policy.next_delay(&Method::GET, attempt, Outcome::Status(StatusCode::SERVICE_UNAVAILABLE, None));
+            assert!(delay.unwrap() <= policy.backoff(attempt));
+        }
+    }
+
+    #[test]
+    fn it_only_retries_configured_statuses_on_idempotent_methods() {
+        let policy = RetryPolicy::new().jitter(false).retry_on(&[503]);
+
+        let unavailable = Outcome::Status(StatusCode::SERVICE_UNAVAILABLE, None);
+        assert_eq!(policy.next_delay(&Method::GET, 1, unavailable), Some(Duration::from_millis(250)));
+        assert_eq!(policy.next_delay(&Method::GET, 1, Outcome::Status(StatusCode::BAD_GATEWAY, None)), None);
+        assert_eq!(policy.next_delay(&Method::POST, 1, Outcome::Status(StatusCode::SERVICE_UNAVAILABLE, None)), None);
+        assert_eq!(policy.next_delay(&Method::GET, 3, Outcome::Status(StatusCode::SERVICE_UNAVAILABLE, None)), None);
+    }
+
+    #[test]
+    fn it_honours_retry_after_hints() {
+        let policy = RetryPolicy::new().jitter(false);
+        let outcome = Outcome::Status(StatusCode::TOO_MANY_REQUESTS, Some(Duration::from_secs(2)));
+
+        assert_eq!(policy.next_delay(&Method::GET, 1, outcome), Some(Duration::from_secs(2)));
+    }
+}
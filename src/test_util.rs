@@ -0,0 +1,336 @@
+//! An in-process fake of the SportMonks API, for testing code built on this client.
+//!
+//! Enabled by the `test-util` feature. The server answers on the real route shapes with the
+//! payloads this crate is tested against, so the data does not depend on the ids requested.
+use reqwest::StatusCode;
+use serde_json::{self, json, Value};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const DEFAULT_PER_PAGE: usize = 100;
+
+/// The payloads served until routes are registered over them.
+const CORPUS: &[(&str, &str)] = &[
+    ("/commentaries/fixture/{id}", include_str!("support/comments/all_for.json")),
+    ("/continents", include_str!("support/continents/all_with.json")),
+    ("/continents/{id}", include_str!("support/continents/find_with.json")),
+    ("/countries", include_str!("support/countries/all_with.json")),
+    ("/countries/{id}", include_str!("support/countries/find_with.json")),
+    ("/fixtures/{id}", include_str!("support/fixtures/find_with_many_stuff.json")),
+    ("/fixtures/multi/{ids}", include_str!("support/fixtures/filter_with.json")),
+    ("/fixtures/date/{date}", include_str!("support/fixtures/on_with.json")),
+    ("/fixtures/between/{start}/{end}", include_str!("support/fixtures/on_with.json")),
+    ("/fixtures/between/{start}/{end}/{team_id}", include_str!("support/fixtures/team_between_with.json")),
+    ("/head2head/{team_one_id}/{team_two_id}", include_str!("support/head2head/find.json")),
+    ("/leagues", include_str!("support/leagues/all_with.json")),
+    ("/leagues/{id}", include_str!("support/leagues/find_with.json")),
+    ("/livescores", include_str!("support/livescores/all_with.json")),
+    ("/livescores/now", include_str!("support/livescores/now_with.json")),
+    ("/players/{id}", include_str!("support/players/find_with.json")),
+    ("/seasons", include_str!("support/seasons/all.json")),
+    ("/seasons/{id}", include_str!("support/seasons/find_with_results.json")),
+    ("/standings/season/{id}", include_str!("support/standings/find.json")),
+    ("/standings/season/live/{id}", include_str!("support/standings/live.json")),
+    ("/teams/{id}", include_str!("support/teams/find_with.json")),
+    ("/teams/season/{id}", include_str!("support/teams/of_season_with.json")),
+    ("/topscorers/season/{id}", include_str!("support/topscorers/by_season.json")),
+    ("/topscorers/season/{id}/aggregated", include_str!("support/topscorers/aggregated.json")),
+];
+
+/// A fake SportMonks API listening on a local port until dropped.
+///
+/// Routes are patterns where `{...}` segments match anything, e.g. `/fixtures/{id}`. The most
+/// recently registered matching route answers. List payloads are paginated according to the
+/// `page` and `per_page` query parameters, requests without an `api_token` are refused with a
+/// 401 and unknown routes answer a 404, both as SportMonks error payloads.
+pub struct FakeServer {
+    address: SocketAddr,
+    state: Arc<Mutex<State>>,
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+struct State {
+    routes: Vec<Route>,
+    per_page: usize,
+    requests: Vec<String>,
+}
+
+struct Route {
+    pattern: String,
+    status: u16,
+    retry_after: Option<Duration>,
+    body: String,
+}
+
+/// A parsed request line.
+struct Request {
+    path: String,
+    query: Vec<(String, String)>,
+}
+
+impl Request {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.query.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+}
+
+impl FakeServer {
+    /// Starts a server on a free local port, serving the built-in payloads.
+    pub fn start() -> FakeServer {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("could not bind the fake SportMonks server");
+        let address = listener.local_addr().expect("could not read the fake SportMonks server address");
+        let routes = CORPUS.iter()
+            .map(|&(pattern, body)| Route { pattern: pattern.to_string(), status: 200, retry_after: None, body: body.to_string() })
+            .collect();
+        let state = Arc::new(Mutex::new(State { routes, per_page: DEFAULT_PER_PAGE, requests: Vec::new() }));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let (thread_state, thread_running) = (state.clone(), running.clone());
+        let handle = thread::spawn(move || {
+            for stream in listener.incoming() {
+                if !thread_running.load(Ordering::SeqCst) {
+                    return;
+                }
+                if let Ok(stream) = stream {
+                    serve(stream, &thread_state);
+                }
+            }
+        });
+        FakeServer { address, state, running, handle: Some(handle) }
+    }
+
+    /// The base URL to hand to `ClientBuilder::base_url`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Answers requests matching `route` with `body`.
+    pub fn register<S: Into<String>>(&self, route: &str, body: S) -> &FakeServer {
+        self.route(route, 200, None, body.into())
+    }
+
+    /// Answers requests matching `route` with a SportMonks error payload.
+    pub fn fail(&self, route: &str, status: u16, message: &str) -> &FakeServer {
+        self.route(route, status, None, error_body(status, message))
+    }
+
+    /// Answers requests matching `route` with a 429 and a `Retry-After` header.
+    pub fn rate_limit(&self, route: &str, retry_after: Duration) -> &FakeServer {
+        self.route(route, 429, Some(retry_after), error_body(429, "Too Many Attempts."))
+    }
+
+    /// Page size of list payloads when the request does not set `per_page`, 100 by default.
+    pub fn per_page(&self, per_page: usize) -> &FakeServer {
+        self.state().per_page = per_page.max(1);
+        self
+    }
+
+    /// Path and query of every request received so far, in order.
+    pub fn requests(&self) -> Vec<String> {
+        self.state().requests.clone()
+    }
+
+    fn route(&self, pattern: &str, status: u16, retry_after: Option<Duration>, body: String) -> &FakeServer {
+        self.state().routes.push(Route { pattern: pattern.to_string(), status, retry_after, body });
+        self
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        // Wakes the listener up so that it notices the server stopped.
+        let _ = TcpStream::connect(self.address);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn serve(mut stream: TcpStream, state: &Mutex<State>) {
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+    let target = match read_target(&stream) {
+        Some(target) => target,
+        None => return,
+    };
+    let (status, retry_after, body) = {
+        let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.requests.push(target.clone());
+        respond(&state, &parse_target(&target))
+    };
+    let reason = StatusCode::from_u16(status).ok().and_then(|status| status.canonical_reason()).unwrap_or("");
+    let mut head = format!("HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n", status, reason, body.len());
+    if let Some(retry_after) = retry_after {
+        head.push_str(&format!("Retry-After: {}\r\n", retry_after.as_secs()));
+    }
+    let _ = stream.write_all(head.as_bytes())
+        .and_then(|_| stream.write_all(b"\r\n"))
+        .and_then(|_| stream.write_all(body.as_bytes()));
+}
+
+/// Reads the request head and returns the target of its request line.
+fn read_target(stream: &TcpStream) -> Option<String> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    loop {
+        let mut header = String::new();
+        match reader.read_line(&mut header) {
+            Ok(0) | Err(_) => break,
+            Ok(_) if header.trim().is_empty() => break,
+            Ok(_) => (),
+        }
+    }
+    request_line.split_whitespace().nth(1).map(String::from)
+}
+
+fn parse_target(target: &str) -> Request {
+    let mut parts = target.splitn(2, '?');
+    let path = parts.next().unwrap_or("").to_string();
+    let query = parts.next().unwrap_or("")
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let mut pair = pair.splitn(2, '=');
+            (decode(pair.next().unwrap_or("")), decode(pair.next().unwrap_or("")))
+        })
+        .collect();
+    Request { path, query }
+}
+
+fn decode(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = bytes.get(index + 1..index + 3)
+            .and_then(|hex| ::std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], escaped) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            },
+            (b'+', _) => {
+                decoded.push(b' ');
+                index += 1;
+            },
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            },
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn respond(state: &State, request: &Request) -> (u16, Option<Duration>, String) {
+    if request.param("api_token").is_none_or(str::is_empty) {
+        return (401, None, error_body(401, "Unauthenticated."));
+    }
+    let route = state.routes.iter().rev().find(|route| matches_route(&route.pattern, &request.path));
+    match route {
+        Some(route) if route.status == 200 => {
+            let per_page = request.param("per_page").and_then(|value| value.parse().ok()).unwrap_or(state.per_page);
+            let page = request.param("page").and_then(|value| value.parse().ok()).unwrap_or(1);
+            (200, None, paginate(&route.body, page, per_page))
+        },
+        Some(route) => (route.status, route.retry_after, route.body.clone()),
+        None => (404, None, error_body(404, &format!("No route for {}.", request.path))),
+    }
+}
+
+fn matches_route(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.trim_matches('/').split('/').collect();
+    let path: Vec<&str> = path.trim_matches('/').split('/').collect();
+    pattern.len() == path.len() && pattern.iter().zip(&path).all(|(expected, actual)| {
+        (expected.starts_with('{') && expected.ends_with('}') && !actual.is_empty()) || expected == actual
+    })
+}
+
+/// Slices the `data` list of `body` to the requested page and rewrites `meta.pagination`.
+fn paginate(body: &str, page: usize, per_page: usize) -> String {
+    let mut payload: Value = match serde_json::from_str(body) {
+        Ok(payload) => payload,
+        Err(_) => return body.to_string(),
+    };
+    let per_page = per_page.max(1);
+    let items = match payload.get_mut("data") {
+        Some(&mut Value::Array(ref mut items)) => items.split_off(0),
+        _ => return body.to_string(),
+    };
+    let total = items.len();
+    let total_pages = total.div_ceil(per_page).max(1);
+    let current_page = page.clamp(1, total_pages);
+    let data: Vec<Value> = items.into_iter().skip((current_page - 1) * per_page).take(per_page).collect();
+    let pagination = json!({
+        "total": total,
+        "count": data.len(),
+        "per_page": per_page,
+        "current_page": current_page,
+        "total_pages": total_pages,
+    });
+    payload["data"] = Value::Array(data);
+    if !payload["meta"].is_object() {
+        payload["meta"] = json!({});
+    }
+    payload["meta"]["pagination"] = pagination;
+    payload.to_string()
+}
+
+fn error_body(status: u16, message: &str) -> String {
+    json!({ "error": { "message": message, "code": status } }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use builder::ClientBuilder;
+    use errors::SportMonksError;
+    use gateway::Options;
+    use models::League;
+
+    #[test]
+    fn it_serves_the_corpus_on_the_real_routes() {
+        let server = FakeServer::start();
+        let client = ClientBuilder::new("1234").base_url(server.url()).build().unwrap();
+
+        assert!(client.fixtures.find(1).unwrap().data.goals.is_some());
+        assert_eq!(client.livescores.now().unwrap().data.len(), 3);
+        assert!(client.standings.find(12962).is_ok());
+
+        server.register("/fixtures/{id}", include_str!("support/fixtures/find.json"));
+        assert!(client.fixtures.find(2).unwrap().data.goals.is_none());
+        assert_eq!(server.requests()[0], "/fixtures/1?api_token=1234");
+    }
+
+    #[test]
+    fn it_paginates_lists_and_answers_errors() {
+        let server = FakeServer::start();
+        server.per_page(3).fail("/leagues/{id}", 403, "No access.").rate_limit("/livescores", Duration::from_secs(7));
+        let client = ClientBuilder::new("1234").base_url(server.url()).build().unwrap();
+
+        let leagues: Vec<League> = client.leagues.all_pages(Options::empty()).items().map(Result::unwrap).collect();
+        assert_eq!(leagues.len(), 8);
+        assert_eq!(server.requests().len(), 3);
+
+        assert_eq!(client.leagues.find(2).unwrap_err().message(), "No access.");
+        assert_eq!(client.livescores.all().unwrap_err().retry_after(), Some(Duration::from_secs(7)));
+        match ClientBuilder::new("1234").base_url(server.url()).build_gateway().unwrap().get::<Value>("/bookmakers", Options::empty()).unwrap_err() {
+            SportMonksError::Api { code, .. } => assert_eq!(code, 404),
+            other => panic!("unexpected error {:?}", other)
+        }
+
+        let anonymous = ClientBuilder::new("").base_url(server.url()).build().unwrap();
+        assert_eq!(anonymous.contients.all().unwrap_err().code(), 401);
+    }
+}
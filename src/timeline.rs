@@ -0,0 +1,240 @@
+use super::models::{Fixture, Side};
+use std::collections::HashSet;
+
+/// What happened at a point of a match feed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TimelineKind {
+    Goal,
+    OwnGoal,
+    Penalty,
+    YellowCard,
+    /// A straight red or a second yellow.
+    RedCard,
+    Substitution,
+    Corner,
+    Commentary,
+    /// An event type without a dedicated kind, e.g. `missed_penalty` or `var`, kept verbatim.
+    Other(String),
+}
+
+impl TimelineKind {
+    fn from_event(kind: &str) -> TimelineKind {
+        match kind {
+            "goal" => TimelineKind::Goal,
+            "own-goal" => TimelineKind::OwnGoal,
+            "penalty" => TimelineKind::Penalty,
+            "yellowcard" => TimelineKind::YellowCard,
+            "redcard" | "yellowred" => TimelineKind::RedCard,
+            "substitution" | "subst" => TimelineKind::Substitution,
+            other => TimelineKind::Other(other.to_string()),
+        }
+    }
+
+    pub fn is_goal(&self) -> bool {
+        matches!(*self, TimelineKind::Goal | TimelineKind::OwnGoal | TimelineKind::Penalty)
+    }
+}
+
+/// The score of both sides at a point of the timeline.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Score {
+    pub home: i64,
+    pub away: i64,
+}
+
+impl Score {
+    fn parse(result: &str) -> Option<Score> {
+        let mut goals = result.split('-').map(|goals| goals.trim().parse::<i64>());
+        match (goals.next(), goals.next(), goals.next()) {
+            (Some(Ok(home)), Some(Ok(away)), None) => Some(Score { home, away }),
+            _ => None,
+        }
+    }
+}
+
+/// One entry of `Fixture::timeline`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineEntry {
+    pub minute: i64,
+    pub extra_minute: Option<i64>,
+    pub kind: TimelineKind,
+    /// `None` for commentary and for teams playing neither side.
+    pub side: Option<Side>,
+    pub team_id: Option<i64>,
+    /// The scorer, booked player or player coming on.
+    pub player_name: Option<String>,
+    /// The assisting player or player going off.
+    pub related_player_name: Option<String>,
+    /// The commentary line, or the corner's own comment.
+    pub text: Option<String>,
+    /// The score once this entry happened.
+    pub score: Score,
+}
+
+impl TimelineEntry {
+    fn new(minute: Option<i64>, extra_minute: Option<i64>, kind: TimelineKind) -> TimelineEntry {
+        TimelineEntry {
+            minute: minute.unwrap_or(0),
+            extra_minute,
+            kind,
+            side: None,
+            team_id: None,
+            player_name: None,
+            related_player_name: None,
+            text: None,
+            score: Score::default(),
+        }
+    }
+
+    fn team(mut self, fixture: &Fixture, team_id: i64) -> TimelineEntry {
+        self.side = fixture.side_of(team_id);
+        self.team_id = Some(team_id);
+        self
+    }
+
+    fn players(mut self, player_name: Option<String>, related_player_name: Option<String>) -> TimelineEntry {
+        self.player_name = player_name;
+        self.related_player_name = related_player_name;
+        self
+    }
+}
+
+impl Fixture {
+    /// Merges the `goals`, `cards`, `substitutions`, `events`, `corners` and `comments` includes
+    /// into one list ordered by minute and extra minute.
+    ///
+    /// `events` only contributes what the dedicated includes did not load, so requesting both
+    /// does not duplicate entries. The running score follows the `result` of goals when the API
+    /// sends it and otherwise counts each goal for the side of its team. Own goals carry the
+    /// team of the player who scored them, so they count for the other side.
+    pub fn timeline(&self) -> Vec<TimelineEntry> {
+        let mut entries = Vec::new();
+        let mut seen = HashSet::new();
+
+        for goal in self.goals.iter().flatten() {
+            seen.insert(goal.id);
+            let mut entry = TimelineEntry::new(goal.minute, goal.extra_minute, TimelineKind::from_event(&goal.kind))
+                .team(self, goal.team_id)
+                .players(goal.player_name.clone(), goal.player_assist_name.clone());
+            entry.score = Score::parse(&goal.result).unwrap_or_default();
+            entries.push(entry);
+        }
+        for card in self.cards.iter().flatten() {
+            seen.insert(card.id);
+            entries.push(TimelineEntry::new(Some(card.minute), card.extra_minute, TimelineKind::from_event(&card.kind))
+                .team(self, card.team_id)
+                .players(Some(card.player_name.clone()), None));
+        }
+        for substitution in self.substitutions.iter().flatten() {
+            seen.insert(substitution.id);
+            entries.push(TimelineEntry::new(Some(substitution.minute), substitution.extra_minute, TimelineKind::Substitution)
+                .team(self, substitution.team_id)
+                .players(Some(substitution.player_in_name.clone()), Some(substitution.player_out_name.clone())));
+        }
+        for event in self.events.iter().flatten().filter(|event| !seen.contains(&event.id)) {
+            let mut entry = TimelineEntry::new(Some(event.minute), event.extra_minute, TimelineKind::from_event(&event.kind))
+                .team(self, event.team_id)
+                .players(Some(event.player_name.clone()), event.related_player_name.clone());
+            entry.score = event.result.as_ref().and_then(|result| Score::parse(result)).unwrap_or_default();
+            entries.push(entry);
+        }
+        for corner in self.corners.iter().flatten() {
+            let mut entry = TimelineEntry::new(Some(corner.minute), corner.extra_minute, TimelineKind::Corner)
+                .team(self, corner.team_id);
+            entry.text = Some(corner.comment.clone());
+            entries.push(entry);
+        }
+        for comment in self.comments.iter().flatten() {
+            let mut entry = TimelineEntry::new(comment.minute, comment.extra_minute, TimelineKind::Commentary);
+            entry.text = Some(comment.comment.clone());
+            entries.push(entry);
+        }
+
+        entries.sort_by_key(|entry| (entry.minute, entry.extra_minute.unwrap_or(0)));
+        keep_score(&mut entries);
+        entries
+    }
+}
+
+fn keep_score(entries: &mut [TimelineEntry]) {
+    let mut score = Score::default();
+    for entry in entries {
+        if entry.kind.is_goal() {
+            if entry.score != Score::default() {
+                score = entry.score;
+            } else {
+                match (entry.side, entry.kind == TimelineKind::OwnGoal) {
+                    (Some(Side::Home), false) | (Some(Side::Away), true) => score.home += 1,
+                    (Some(Side::Away), false) | (Some(Side::Home), true) => score.away += 1,
+                    (None, _) => (),
+                }
+            }
+        }
+        entry.score = score;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use models::{CardEvent, Comment, GoalEvent, Substitution};
+
+    fn fixture() -> Fixture {
+        Fixture {
+            localteam_id: 1,
+            visitorteam_id: 2,
+            goals: Some(vec![
+                GoalEvent { id: 10, team_id: 2, kind: "goal".into(), minute: Some(70), result: "1-1".into(), ..GoalEvent::default() },
+                GoalEvent { id: 11, team_id: 1, kind: "penalty".into(), minute: Some(12), result: "".into(), ..GoalEvent::default() },
+            ]),
+            cards: Some(vec![
+                CardEvent { id: 12, team_id: 2, kind: "yellowred".into(), minute: 45, extra_minute: Some(2), ..CardEvent::default() },
+            ]),
+            substitutions: Some(vec![
+                Substitution { id: 13, team_id: 1, minute: 45, player_in_name: "In".into(), player_out_name: "Out".into(), ..Substitution::default() },
+            ]),
+            comments: Some(vec![
+                Comment { minute: Some(90), comment: "Full time.".into(), ..Comment::default() },
+            ]),
+            ..Fixture::default()
+        }
+    }
+
+    #[test]
+    fn it_orders_the_timeline_and_keeps_the_score() {
+        let timeline = fixture().timeline();
+        let kinds: Vec<_> = timeline.iter().map(|entry| entry.kind.clone()).collect();
+
+        assert_eq!(kinds, vec![
+            TimelineKind::Penalty,
+            TimelineKind::Substitution,
+            TimelineKind::RedCard,
+            TimelineKind::Goal,
+            TimelineKind::Commentary,
+        ]);
+        assert_eq!(timeline[0].score, Score { home: 1, away: 0 });
+        assert_eq!(timeline[2].side, Some(Side::Away));
+        assert_eq!(timeline[3].score, Score { home: 1, away: 1 });
+        assert_eq!(timeline[4].score, Score { home: 1, away: 1 });
+        assert_eq!(timeline[1].related_player_name, Some("Out".to_string()));
+    }
+
+    #[test]
+    fn it_counts_own_goals_for_the_other_side() {
+        let fixture = Fixture {
+            localteam_id: 1,
+            visitorteam_id: 2,
+            goals: Some(vec![
+                GoalEvent { id: 10, team_id: 2, kind: "own-goal".into(), minute: Some(30), result: "".into(), ..GoalEvent::default() },
+                GoalEvent { id: 11, team_id: 1, kind: "own-goal".into(), minute: Some(60), result: "".into(), ..GoalEvent::default() },
+            ]),
+            ..Fixture::default()
+        };
+
+        let timeline = fixture.timeline();
+
+        assert_eq!(timeline[0].side, Some(Side::Away));
+        assert_eq!(timeline[0].score, Score { home: 1, away: 0 });
+        assert_eq!(timeline[1].score, Score { home: 1, away: 1 });
+    }
+}
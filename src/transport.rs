@@ -0,0 +1,132 @@
+use super::errors::SportMonksError;
+use reqwest::{Client, StatusCode};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::time::Duration;
+
+/// A GET request as the `Gateway` sends it, `api_token` included in the query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    pub url: String,
+    pub query: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    /// The value of the query parameter `name`, if sent.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.query.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+}
+
+/// A response read in full by a `Transport`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    /// The `Retry-After` header, in seconds.
+    pub retry_after: Option<Duration>,
+    pub body: String,
+}
+
+/// Sends the requests of a `Gateway`.
+///
+/// `reqwest::Client` is the default transport. Other implementations can route requests
+/// through a corporate egress client, another TLS stack or an in-memory stub. Transports
+/// that do not use reqwest report a failure worth retrying as `SportMonksError::Unreachable`.
+/// Only that variant, `SportMonksError::Transport` and `SportMonksError::Timeout` are retried
+/// by the gateway's retry policy; other errors are returned as is.
+pub trait Transport: Send + Sync {
+    fn get(&self, request: &HttpRequest) -> Result<HttpResponse, SportMonksError>;
+}
+
+impl Transport for Client {
+    fn get(&self, request: &HttpRequest) -> Result<HttpResponse, SportMonksError> {
+        let mut response = Client::get(self, request.url.as_str()).query(&request.query).send()?;
+        Ok(HttpResponse {
+            status: response.status(),
+            retry_after: retry_after(response.headers()),
+            body: response.text()?,
+        })
+    }
+}
+
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers.get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::Client as SportMonksClient;
+    use gateway::{Gateway, Options};
+    use includes::FixtureInclude;
+    use retry::RetryPolicy;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+
+    /// Answers every request with the same body and keeps the requests.
+    struct Stub {
+        body: String,
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl Transport for Stub {
+        fn get(&self, request: &HttpRequest) -> Result<HttpResponse, SportMonksError> {
+            self.requests.lock().unwrap().push(request.clone());
+            Ok(HttpResponse { status: StatusCode::OK, retry_after: None, body: self.body.clone() })
+        }
+    }
+
+    /// Drops the connection of the first request, then answers like `Stub`.
+    struct Flaky {
+        stub: Stub,
+        failed: Mutex<bool>,
+    }
+
+    impl Transport for Flaky {
+        fn get(&self, request: &HttpRequest) -> Result<HttpResponse, SportMonksError> {
+            let mut failed = self.failed.lock().unwrap();
+            if !*failed {
+                *failed = true;
+                return Err(SportMonksError::Unreachable(Box::new(io::Error::new(io::ErrorKind::ConnectionReset, "connection reset"))));
+            }
+            self.stub.get(request)
+        }
+    }
+
+    #[test]
+    fn it_sends_the_requests_of_the_ops_through_the_transport() {
+        let stub = Arc::new(Stub { body: include_str!("support/fixtures/find_with.json").into(), requests: Mutex::new(Vec::new()) });
+        let client = SportMonksClient::with_gateway(Gateway::with_transport(stub.clone(), "1234".into(), "https://example.test/v2".into()));
+
+        let fixture = client.fixtures.find_with(11414776, Options::builder().with(FixtureInclude::LocalTeam).with(FixtureInclude::Lineup).per_page(20)).unwrap();
+
+        assert_eq!(fixture.data.id, 11414776);
+        assert_eq!(*stub.requests.lock().unwrap(), vec![HttpRequest {
+            url: "https://example.test/v2/fixtures/11414776".into(),
+            query: vec![
+                ("api_token".into(), "1234".into()),
+                ("include".into(), "localTeam,lineup".into()),
+                ("per_page".into(), "20".into()),
+            ],
+        }]);
+    }
+
+    #[test]
+    fn it_retries_unreachable_failures_of_custom_transports() {
+        let flaky = Arc::new(Flaky {
+            stub: Stub { body: include_str!("support/fixtures/find_with.json").into(), requests: Mutex::new(Vec::new()) },
+            failed: Mutex::new(false),
+        });
+        let gateway = Gateway::with_transport(flaky.clone(), "1234".into(), "https://example.test/v2".into())
+            .retry_policy(RetryPolicy::new().max_attempts(2).base_delay(Duration::from_millis(1)));
+        let client = SportMonksClient::with_gateway(gateway);
+
+        let fixture = client.fixtures.find(11414776).unwrap();
+
+        assert_eq!(fixture.data.id, 11414776);
+        assert!(*flaky.failed.lock().unwrap());
+        assert_eq!(flaky.stub.requests.lock().unwrap().len(), 1);
+    }
+}